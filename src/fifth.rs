@@ -1,9 +1,13 @@
-use std::ptr;
+use std::{fmt, marker::PhantomData, ptr};
 
 #[derive(Debug)]
 pub struct List<T> {
     head: *mut Node<T>,
     tail: *mut Node<T>,
+    // number of nodes in the list
+    len: usize,
+    // maximum number of nodes try_push_bounded allows, None when unbounded
+    limit: Option<usize>,
 }
 
 impl<T> List<T> {
@@ -12,9 +16,42 @@ impl<T> List<T> {
         Self {
             head: ptr::null_mut(),
             tail: ptr::null_mut(),
+            len: 0,
+            limit: None,
         }
     }
 
+    // creates an empty list whose try_push_bounded stops at limit items
+    // the limit is not a capacity, push, push_front, append and extend
+    // do not check it and can grow the list past it
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    // returns the limit of the list, None when unbounded
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    // returns true if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.head.is_null()
+    }
+
+    // returns true if the list has exactly one item
+    // which is when head and tail point to the same node
+    pub fn is_single(&self) -> bool {
+        !self.head.is_null() && self.head == self.tail
+    }
+
+    // returns the number of items in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
     // adds a new node in the list in the back
     pub fn push(&mut self, elem: T) {
         // first create a box so that data is allocated on the heap and owned by the box
@@ -32,6 +69,34 @@ impl<T> List<T> {
         }
         // set tail to the new_node
         self.tail = new_node;
+        self.len += 1;
+    }
+
+    // adds a new node in the list in the back if the list is not full
+    // returns the item back as Err(T) when the list is at its limit
+    // push does not check the limit and can always add to the list
+    pub fn try_push_bounded(&mut self, elem: T) -> Result<(), T> {
+        if self.limit.is_some_and(|limit| self.len >= limit) {
+            return Err(elem);
+        }
+        self.push(elem);
+        Ok(())
+    }
+
+    // adds a new node in the list in the front
+    pub fn push_front(&mut self, elem: T) {
+        let new_node = Box::into_raw(Box::new(Node::new(elem)));
+        unsafe {
+            // next of new_node will now point to the current head
+            (*new_node).next = self.head;
+        }
+        // if tail is null that means list is empty and pushing item for the first time
+        if self.tail.is_null() {
+            self.tail = new_node;
+        }
+        // set head to the new_node
+        self.head = new_node;
+        self.len += 1;
     }
 
     // removes a node from the list
@@ -53,6 +118,7 @@ impl<T> List<T> {
                 if self.head.is_null() {
                     self.tail = ptr::null_mut();
                 }
+                self.len -= 1;
                 // return the element
                 Some(head.elem)
             }
@@ -71,7 +137,8 @@ impl<T> List<T> {
     }
 
     // returns mutable reference to the first element from the front
-    pub fn peek_mut(&self) -> Option<&mut T> {
+    // takes &mut self so that only one mutable reference can exist at a time
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
         // if head is null then return None
         if self.head.is_null() {
             None
@@ -81,10 +148,147 @@ impl<T> List<T> {
         }
     }
 
-    // creates an insatance of IntoIter for the list
-    // also takes ownership of the list
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    // returns the reference to the last element from the back
+    pub fn peek_back(&self) -> Option<&T> {
+        // if tail is null then return None
+        if self.tail.is_null() {
+            None
+        } else {
+            // dereference tail and take referece to the element inside
+            unsafe { Some(&(*self.tail).elem) }
+        }
+    }
+
+    // returns mutable reference to the last element from the back
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        // if tail is null then return None
+        if self.tail.is_null() {
+            None
+        } else {
+            // dereference tail and take mutable referece to the element inside
+            unsafe { Some(&mut (*self.tail).elem) }
+        }
+    }
+
+    // returns the node at the given index
+    // returns null if the index is out of range
+    fn node_at(&self, index: usize) -> *mut Node<T> {
+        let mut curr = self.head;
+        let mut i = 0;
+        // stop at the null pointer so that it is never dereferenced
+        while !curr.is_null() && i < index {
+            unsafe {
+                curr = (*curr).next;
+            }
+            i += 1;
+        }
+        curr
+    }
+
+    // returns the reference to the element at the given index
+    // returns None if the index is out of range
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let node = self.node_at(index);
+        if node.is_null() {
+            None
+        } else {
+            unsafe { Some(&(*node).elem) }
+        }
+    }
+
+    // returns mutable reference to the element at the given index
+    // takes &mut self so that only one mutable reference can exist at a time
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let node = self.node_at(index);
+        if node.is_null() {
+            None
+        } else {
+            unsafe { Some(&mut (*node).elem) }
+        }
+    }
+
+    // copies the items from head to tail into a vec
+    // leaves the list intact
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    // returns true if the list has an item equal to the target
+    // stops at the first match
+    pub fn contains(&self, target: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|elem| elem == target)
+    }
+
+    // returns the index of the first item equal to the target
+    pub fn position(&self, target: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|elem| elem == target)
+    }
+
+    // returns true if following the next pointers from head never reaches null
+    // uses Floyd's tortoise and hare, moving one pointer one step and the other
+    // two steps at a time, they can only meet if the chain loops back on itself
+    // meant as a diagnostic for a chain broken by a bug, a list built through
+    // the methods of this type never has a cycle
+    pub fn detect_cycle(&self) -> bool {
+        let mut slow = self.head;
+        let mut fast = self.head;
+        unsafe {
+            // fast is checked before every step so null is never dereferenced
+            while !fast.is_null() && !(*fast).next.is_null() {
+                slow = (*slow).next;
+                fast = (*(*fast).next).next;
+                if slow == fast {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    // moves all the nodes of other to the back of the list in O(1)
+    // other becomes empty after the call
+    pub fn append(&mut self, other: &mut List<T>) {
+        // if other is empty then nothing to move
+        if other.head.is_null() {
+            return;
+        }
+        // if tail is null that means list is empty and takes the chain of other
+        if self.tail.is_null() {
+            self.head = other.head;
+        } else {
+            unsafe {
+                // next of current tail will now point to the head of other
+                (*self.tail).next = other.head;
+            }
+        }
+        // tail of other becomes the tail of the list
+        self.tail = other.tail;
+        self.len += other.len;
+        // reset other so that the moved nodes are not freed twice
+        other.head = ptr::null_mut();
+        other.tail = ptr::null_mut();
+        other.len = 0;
+    }
+
+    // removes all the nodes from the list
+    // frees every node and resets head and tail to null
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    // creates an instance of Drain for the list
+    // yields the elements from the front while emptying the list
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { list: self }
     }
 
     // creates an instance of Iter for the list
@@ -101,26 +305,103 @@ impl<T> List<T> {
     }
 
     // creates an instance of IterMut for the list
-    pub fn iter_mut<'a>(&'a self) -> IterMut<'a, T> {
-        // if head is null then list is empty
-        let pointer = if self.head.is_null() {
-            None
-        } else {
-            // dereference head and take reference to the node inside
-            unsafe { Some(&mut (*self.head)) }
-        };
+    // takes &mut self so that the list can not be read or changed
+    // while the mutable references handed out by the iterator are alive
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            pointer: self.head,
+            marker: PhantomData,
+        }
+    }
+}
 
-        IterMut { pointer }
+// implement Default for the list returning an empty list
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// implement Clone for the list
+// allocates a brand new chain of nodes, so the clone shares no pointers with the original
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        let mut list = Self::new();
+        list.limit = self.limit;
+        let mut curr = self.head;
+        // walk the next pointers until null is reached
+        while !curr.is_null() {
+            // curr is non null and points to a node owned by self
+            let elem = unsafe { (*curr).elem.clone() };
+            list.push(elem);
+            curr = unsafe { (*curr).next };
+        }
+        list
+    }
+}
+
+// implement FromIterator for the list
+// elements are pushed in the back so the iteration order is preserved
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+// implement Extend for the list
+// elements are pushed in the back one by one
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+// implement PartialEq for the list
+// walks both lists together comparing each item
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // lists of different length can never be equal
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+// implement Display for the list
+// formats the items from head to tail (FIFO order) as [a, b, c]
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        // iter stops at the null pointer, so no null is ever dereferenced
+        for (i, elem) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", elem)?;
+        }
+        write!(f, "]")
     }
 }
 
 // implement Drop for the list to make sure all allocated memory are cleaned up
 impl<T> Drop for List<T> {
     fn drop(&mut self) {
-        while let Some(_) = self.pop() {}
+        self.clear();
     }
 }
 
+// head and tail are raw pointers, so the list is neither Send nor Sync by default
+// head owns the chain of nodes and tail only aliases the last node of that chain,
+// no node is ever shared with another list, so sending or sharing the list
+// is the same as sending or sharing the items it owns
+// Iter and IterMut have their own impls below
+unsafe impl<T: Send> Send for List<T> {}
+unsafe impl<T: Sync> Sync for List<T> {}
+
 #[derive(Debug)]
 struct Node<T> {
     elem: T,
@@ -146,10 +427,67 @@ impl<T> Iterator for IntoIter<T> {
     }
 }
 
+// implement IntoIterator for the list
+// creates an instance of IntoIter for the list
+// also takes ownership of the list
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+// implement IntoIterator for reference of the list
+// this will allow to use &list in for loops
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// implement IntoIterator for mutable reference of the list
+// this will allow to use &mut list in for loops
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// removes the elements from the front of the list one by one
+// when dropped, the remaining elements are removed as well
+// so the list is always empty afterwards
+pub struct Drain<'a, T> {
+    list: &'a mut List<T>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop()
+    }
+}
+
+// implement Drop for Drain to empty the list even if not fully consumed
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.list.clear();
+    }
+}
+
 pub struct Iter<'a, T> {
     pointer: Option<&'a Node<T>>,
 }
 
+// Iter only hands out shared references, so it behaves like &List
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -165,22 +503,36 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+// walks the raw next pointers and only ever creates a mutable reference
+// to the element of a node, never to the node itself, so the references
+// handed out never overlap with each other or with the links
 pub struct IterMut<'a, T> {
-    pointer: Option<&'a mut Node<T>>,
+    pointer: *mut Node<T>,
+    // ties the iterator to the mutable borrow of the list
+    marker: PhantomData<&'a mut T>,
 }
 
+// IterMut hands out mutable references, so it behaves like &mut List
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
 impl<'a, T: 'a> Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.pointer.take().map(|node| {
-            self.pointer = if node.next.is_null() {
-                None
-            } else {
-                unsafe { Some(&mut *node.next) }
-            };
-            &mut node.elem
-        })
+        // if pointer is null then all nodes are visited
+        if self.pointer.is_null() {
+            None
+        } else {
+            unsafe {
+                let node = self.pointer;
+                // move the pointer one step to the next node
+                self.pointer = (*node).next;
+                // every node is visited only once,
+                // so there is only one mutable reference to each element
+                Some(&mut (*node).elem)
+            }
+        }
     }
 }
 
@@ -264,4 +616,435 @@ mod tests {
         assert_eq!(iter.next(), Some(&mut 3));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_default() {
+        let mut list = List::<i32>::default();
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 3);
+        list.pop();
+        assert_eq!(list.len(), 2);
+        list.pop();
+        list.pop();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        assert!(list.head.is_null());
+        assert!(list.tail.is_null());
+        list.pop();
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_peek_mut_and_iter_mut() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        if let Some(elem) = list.peek_mut() {
+            *elem *= 10;
+        }
+        for elem in list.iter_mut() {
+            *elem += 1;
+        }
+        // references handed out by iter_mut can be held together
+        let refs: Vec<&mut i32> = list.iter_mut().collect();
+        assert_eq!(refs, vec![&mut 11, &mut 3, &mut 4]);
+        assert_eq!(list.peek(), Some(&11));
+        assert_eq!(list.pop(), Some(11));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+    }
+
+    #[test]
+    fn test_iter_mut_double() {
+        let mut list = List::new();
+        list.iter_mut().for_each(|e: &mut i32| *e *= 2);
+        for i in 1..=4 {
+            list.push(i);
+        }
+        list.iter_mut().for_each(|e| *e *= 2);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&6));
+        assert_eq!(iter.next(), Some(&8));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_peek_back() {
+        let mut list = List::new();
+        assert_eq!(list.peek_back(), None);
+        assert_eq!(list.peek_back_mut(), None);
+        list.push(1);
+        // front and back are the same element
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&1));
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&3));
+        *list.peek_back_mut().unwrap() += 10;
+        assert_eq!(list.peek_back(), Some(&13));
+        list.pop();
+        list.pop();
+        assert_eq!(list.pop(), Some(13));
+        assert_eq!(list.peek_back(), None);
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut list = List::new();
+        list.push_front(2);
+        list.push(3);
+        list.push_front(1);
+        list.push(4);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&4));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), None);
+        list.push_front(5);
+        list.push(6);
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), Some(6));
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        let mut cloned = list.clone();
+        assert_eq!(cloned.len(), 3);
+        assert_eq!(cloned.peek_back(), Some(&3));
+        assert_eq!(cloned.pop(), Some(1));
+        *cloned.peek_mut().unwrap() = 20;
+        cloned.push(4);
+        // original is not affected by changes in the clone
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek_back(), Some(&3));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+        assert_eq!(cloned.pop(), Some(20));
+        assert_eq!(cloned.pop(), Some(3));
+        assert_eq!(cloned.pop(), Some(4));
+        let empty = List::<i32>::new().clone();
+        assert!(empty.is_empty());
+        assert!(empty.tail.is_null());
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut list: List<i32> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek_back(), Some(&3));
+        list.extend(vec![4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.peek_back(), Some(&5));
+        for i in 1..=5 {
+            assert_eq!(list.pop(), Some(i));
+        }
+        assert_eq!(list.pop(), None);
+        // empty iterators leave the list untouched
+        let mut list: List<i32> = std::iter::empty().collect();
+        assert!(list.head.is_null());
+        assert!(list.tail.is_null());
+        list.extend(Vec::new());
+        assert!(list.head.is_null());
+        assert!(list.tail.is_null());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_into_iterator_for_loops() {
+        let mut list: List<i32> = (1..=3).collect();
+        let mut seen = Vec::new();
+        for elem in &list {
+            seen.push(*elem);
+        }
+        assert_eq!(seen, vec![1, 2, 3]);
+        for elem in &mut list {
+            *elem *= 10;
+        }
+        let mut seen = Vec::new();
+        for elem in list {
+            seen.push(elem);
+        }
+        assert_eq!(seen, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut list = List::new();
+        assert_eq!(list.to_string(), "[]");
+        list.push(1);
+        assert_eq!(list.to_string(), "[1]");
+        list.push(2);
+        list.push(3);
+        assert_eq!(format!("{}", list), "[1, 2, 3]");
+        list.pop();
+        list.push_front(0);
+        assert_eq!(list.to_string(), "[0, 2, 3]");
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list: List<i32> = (1..=2).collect();
+        let mut other: List<i32> = (3..=4).collect();
+        list.append(&mut other);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.peek_back(), Some(&4));
+        assert!(other.is_empty());
+        assert!(other.tail.is_null());
+        assert_eq!(other.len(), 0);
+        // both lists are still usable
+        list.push(5);
+        other.push(6);
+        assert_eq!(list.to_string(), "[1, 2, 3, 4, 5]");
+        assert_eq!(other.to_string(), "[6]");
+        // empty other is a no-op
+        let mut empty = List::new();
+        list.append(&mut empty);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.peek_back(), Some(&5));
+        // empty list takes the nodes of other
+        let mut empty = List::new();
+        empty.append(&mut list);
+        assert_eq!(empty.len(), 5);
+        assert_eq!(empty.peek(), Some(&1));
+        assert_eq!(empty.peek_back(), Some(&5));
+        assert!(list.is_empty());
+        assert!(list.tail.is_null());
+        // both empty
+        let mut a = List::<i32>::new();
+        let mut b = List::<i32>::new();
+        a.append(&mut b);
+        assert!(a.head.is_null());
+        assert!(a.tail.is_null());
+        for i in 1..=5 {
+            assert_eq!(empty.pop(), Some(i));
+        }
+        assert_eq!(empty.pop(), None);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list: List<i32> = (1..=3).collect();
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&3));
+        assert_eq!(list.get(3), None);
+        assert_eq!(list.get(100), None);
+        *list.get_mut(0).unwrap() += 10;
+        *list.get_mut(1).unwrap() += 10;
+        *list.get_mut(2).unwrap() += 10;
+        assert_eq!(list.get_mut(3), None);
+        assert_eq!(list.to_string(), "[11, 12, 13]");
+        let mut empty = List::<i32>::new();
+        assert_eq!(empty.get(0), None);
+        assert_eq!(empty.get_mut(0), None);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list: List<i32> = (1..=3).collect();
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert!(list.head.is_null());
+        assert!(list.tail.is_null());
+        assert_eq!(list.pop(), None);
+        // list is still usable after clear
+        list.push(4);
+        assert_eq!(list.peek(), Some(&4));
+        assert_eq!(list.peek_back(), Some(&4));
+        list.clear();
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut list: List<i32> = (1..=5).collect();
+        let drained: Vec<i32> = list.drain().collect();
+        assert_eq!(drained, vec![1, 2, 3, 4, 5]);
+        assert!(list.is_empty());
+        // dropping a partially consumed drain still empties the list
+        let mut list: List<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let mut drain = list.drain();
+        assert_eq!(drain.next(), Some("a".to_string()));
+        drop(drain);
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert!(list.head.is_null());
+        assert!(list.tail.is_null());
+        list.push("d".to_string());
+        assert_eq!(list.pop(), Some("d".to_string()));
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<S: Send + Sync>() {}
+        assert_send_sync::<List<i32>>();
+        assert_send_sync::<Iter<'_, i32>>();
+        assert_send_sync::<IterMut<'_, i32>>();
+        assert_send_sync::<IntoIter<i32>>();
+        assert_send_sync::<Drain<'_, i32>>();
+
+        let list: List<i32> = (1..=3).collect();
+        let handle = std::thread::spawn(move || {
+            let mut list = list;
+            let mut popped = Vec::new();
+            while let Some(elem) = list.pop() {
+                popped.push(elem);
+            }
+            popped
+        });
+        assert_eq!(handle.join().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_contains_and_position() {
+        let empty = List::<i32>::new();
+        assert!(!empty.contains(&1));
+        assert_eq!(empty.position(&1), None);
+        let list: List<i32> = vec![1, 2, 3, 2].into_iter().collect();
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+        assert_eq!(list.position(&1), Some(0));
+        assert_eq!(list.position(&3), Some(2));
+        // returns the first of the duplicates
+        assert_eq!(list.position(&2), Some(1));
+        assert_eq!(list.position(&4), None);
+    }
+
+    #[test]
+    fn test_eq() {
+        let a: List<i32> = (1..=3).collect();
+        let mut b = List::new();
+        b.push(2);
+        b.push(3);
+        b.push_front(1);
+        assert_eq!(a, b);
+        assert_eq!(List::<i32>::new(), List::new());
+        // different length
+        let c: List<i32> = (1..=2).collect();
+        assert_ne!(a, c);
+        assert_ne!(c, a);
+        assert_ne!(a, List::new());
+        // differ by a single element
+        let d: List<i32> = vec![1, 5, 3].into_iter().collect();
+        assert_ne!(a, d);
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let mut list = List::new();
+        assert_eq!(list.to_vec(), Vec::<i32>::new());
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        // the list is not consumed
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop(), Some(1));
+    }
+
+    #[test]
+    fn test_is_single() {
+        let mut list = List::new();
+        assert!(!list.is_single());
+        list.push(1);
+        assert!(list.is_single());
+        list.push(2);
+        assert!(!list.is_single());
+        list.pop();
+        assert!(list.is_single());
+    }
+
+    // points next of the tail to the node at the given index
+    // or back to null when index is None
+    fn link_tail_to(list: &mut List<i32>, index: Option<usize>) {
+        let target = index.map_or(ptr::null_mut(), |index| list.node_at(index));
+        unsafe {
+            (*list.tail).next = target;
+        }
+    }
+
+    #[test]
+    fn test_detect_cycle() {
+        let mut list = List::new();
+        assert!(!list.detect_cycle());
+        list.push(1);
+        assert!(!list.detect_cycle());
+        // single node pointing to itself
+        link_tail_to(&mut list, Some(0));
+        assert!(list.detect_cycle());
+        link_tail_to(&mut list, None);
+        list.extend(2..=5);
+        assert!(!list.detect_cycle());
+        // tail back to head
+        link_tail_to(&mut list, Some(0));
+        assert!(list.detect_cycle());
+        // tail back to a node in the middle
+        link_tail_to(&mut list, Some(2));
+        assert!(list.detect_cycle());
+        // restore the chain so that the list can be dropped
+        link_tail_to(&mut list, None);
+        assert!(!list.detect_cycle());
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_with_limit() {
+        let mut list = List::with_limit(2);
+        assert_eq!(list.limit(), Some(2));
+        assert!(list.is_empty());
+        assert_eq!(list.try_push_bounded(1), Ok(()));
+        assert_eq!(list.try_push_bounded(2), Ok(()));
+        // the list is full
+        assert_eq!(list.try_push_bounded(3), Err(3));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.peek_back(), Some(&2));
+        assert_eq!(list.pop(), Some(1));
+        // there is room again after popping
+        assert_eq!(list.try_push_bounded(3), Ok(()));
+        assert_eq!(list.try_push_bounded(4), Err(4));
+        assert_eq!(list.to_vec(), vec![2, 3]);
+        // clones keep the limit
+        let mut cloned = list.clone();
+        assert_eq!(cloned.try_push_bounded(4), Err(4));
+        // a list with zero limit rejects every try_push_bounded
+        let mut list = List::with_limit(0);
+        assert_eq!(list.try_push_bounded(1), Err(1));
+        assert!(list.is_empty());
+        // a list without limit is unbounded
+        let mut list = List::new();
+        assert_eq!(list.limit(), None);
+        for i in 0..100 {
+            assert_eq!(list.try_push_bounded(i), Ok(()));
+        }
+        assert_eq!(list.len(), 100);
+    }
 }
@@ -1,16 +1,47 @@
 #[derive(Debug)]
-pub struct List {
-    head: Link,
+pub struct List<T> {
+    head: Link<T>,
 }
 
-impl List {
+impl<T> List<T> {
     // create a blank linked list
     pub fn new() -> Self {
         List { head: Link::Empty }
     }
 
+    // creates a list from the items of the slice
+    // the first item of the slice ends up at the head,
+    // so pop and iter yield the items in the same order as the slice
+    pub fn from_slice(items: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let mut list = List::new();
+        // push adds to the front, so push from the last item to the first
+        for elem in items.iter().rev() {
+            list.push(elem.clone());
+        }
+        list
+    }
+
+    // returns true if the list is empty
+    pub fn is_empty(&self) -> bool {
+        matches!(self.head, Link::Empty)
+    }
+
+    // returns length of the list
+    pub fn len(&self) -> usize {
+        let mut len = 0;
+        let mut curr = &self.head;
+        while let Link::More(node) = curr {
+            len += 1;
+            curr = &node.next;
+        }
+        len
+    }
+
     // push an item into the linked list
-    pub fn push(&mut self, elem: i32) {
+    pub fn push(&mut self, elem: T) {
         // create a new node with empty next
         let mut new_node = Node::new(elem);
         // take out self.head and replace with empty temporarily
@@ -21,7 +52,7 @@ impl List {
         self.head = Link::More(Box::new(new_node));
     }
 
-    pub fn pop(&mut self) -> Option<i32> {
+    pub fn pop(&mut self) -> Option<T> {
         // take out self.head and replace with empty temporarily
         let old_head = std::mem::replace(&mut self.head, Link::Empty);
         match old_head {
@@ -36,9 +67,63 @@ impl List {
             }
         }
     }
+
+    // returns reference to the first item in the list
+    pub fn peek(&self) -> Option<&T> {
+        match &self.head {
+            Link::Empty => None,
+            Link::More(node) => Some(&node.elem),
+        }
+    }
+
+    // returns mutable reference to the first item in the list
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        match &mut self.head {
+            Link::Empty => None,
+            Link::More(node) => Some(&mut node.elem),
+        }
+    }
+
+    // reverse the list in place by relinking the nodes
+    pub fn reverse(&mut self) {
+        // take out self.head and replace with empty temporarily
+        let mut curr = std::mem::replace(&mut self.head, Link::Empty);
+        // holds the part of the list which is already reversed
+        let mut reversed = Link::Empty;
+        while let Link::More(mut node) = curr {
+            // detach the rest of the list from the current node
+            curr = std::mem::replace(&mut node.next, Link::Empty);
+            // current node now points to the reversed part
+            node.next = reversed;
+            // current node becomes the head of the reversed part
+            reversed = Link::More(node);
+        }
+        self.head = reversed;
+    }
+
+    // returns Iter instance of the list
+    pub fn iter(&self) -> Iter<'_, T> {
+        let pointer = match &self.head {
+            Link::Empty => None,
+            Link::More(node) => Some(node.as_ref()),
+        };
+        Iter { pointer }
+    }
+}
+
+impl List<i32> {
+    // returns the sum of all items in the list, 0 for an empty list
+    pub fn sum(&self) -> i32 {
+        self.iter().sum()
+    }
+
+    // returns the product of all items in the list, 1 for an empty list
+    pub fn product(&self) -> i32 {
+        self.iter().product()
+    }
 }
 
-impl Drop for List {
+impl<T> Drop for List<T> {
     fn drop(&mut self) {
         let mut cur_link = std::mem::replace(&mut self.head, Link::Empty);
         while let Link::More(mut node) = cur_link {
@@ -48,19 +133,19 @@ impl Drop for List {
 }
 
 #[derive(Debug)]
-enum Link {
+enum Link<T> {
     Empty,
-    More(Box<Node>),
+    More(Box<Node<T>>),
 }
 
 #[derive(Debug)]
-struct Node {
-    elem: i32,
-    next: Link,
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
 }
 
-impl Node {
-    fn new(elem: i32) -> Self {
+impl<T> Node<T> {
+    fn new(elem: T) -> Self {
         Self {
             elem,
             next: Link::Empty,
@@ -68,6 +153,50 @@ impl Node {
     }
 }
 
+#[derive(Debug)]
+pub struct IntoIter<T>(List<T>);
+
+// Implement Iterator for IntoIter
+// This will allow to iterate over the list
+// and get back each value in the list
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+}
+
+// Implement IntoIterator for List
+// takes ownership of the list so it can be used in for loops
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+pub struct Iter<'a, T> {
+    pointer: Option<&'a Node<T>>,
+}
+
+// Implement Iterator for Iter
+// This will allow to iterate over the list
+// and get back a references over each item
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pointer.take().map(|node| {
+            // move the pointer to the next node if there is one
+            self.pointer = match &node.next {
+                Link::Empty => None,
+                Link::More(nxt) => Some(nxt.as_ref()),
+            };
+            &node.elem
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,4 +218,144 @@ mod tests {
         // when pop from empty list it returns None
         assert_eq!(list.pop(), None);
     }
+
+    #[test]
+    fn test_list_2() {
+        let mut list = List::new();
+        assert_eq!(list.pop(), None);
+        list.push(String::from("a"));
+        list.push(String::from("b"));
+        // works with non-copy element types as well
+        assert_eq!(list.pop(), Some(String::from("b")));
+        assert_eq!(list.pop(), Some(String::from("a")));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut list = List::new();
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.peek_mut(), None);
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.peek_mut(), Some(&mut 3));
+        // mutate the first item in place
+        if let Some(elem) = list.peek_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(list.peek(), Some(&30));
+        assert_eq!(list.pop(), Some(30));
+        assert_eq!(list.peek(), Some(&2));
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        list.push(1);
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 1);
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.len(), 3);
+        list.pop();
+        assert_eq!(list.len(), 2);
+        list.pop();
+        list.pop();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        // pop from empty list does not change the length
+        list.pop();
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list = List::new();
+        assert_eq!(list.iter().next(), None);
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        // iterating does not consume the list
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        let items: Vec<i32> = list.into_iter().collect();
+        assert_eq!(items, vec![3, 2, 1]);
+
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        let mut sum = 0;
+        for elem in list {
+            sum += elem;
+        }
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list: List<i32> = List::new();
+        list.reverse();
+        assert!(list.is_empty());
+
+        list.push(1);
+        list.reverse();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.peek(), Some(&1));
+
+        list.push(2);
+        list.push(3);
+        list.reverse();
+        // items are now popped in the order they were pushed
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let mut list = List::new();
+        assert_eq!(list.sum(), 0);
+        assert_eq!(list.product(), 1);
+        list.push(5);
+        assert_eq!(list.sum(), 5);
+        assert_eq!(list.product(), 5);
+        list.push(2);
+        list.push(3);
+        list.push(-1);
+        assert_eq!(list.sum(), 9);
+        assert_eq!(list.product(), -30);
+        // the list is left untouched
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.peek(), Some(&-1));
+    }
+
+    #[test]
+    fn test_from_slice() {
+        let mut list = List::from_slice(&[1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+        let list = List::<i32>::from_slice(&[]);
+        assert!(list.is_empty());
+    }
 }
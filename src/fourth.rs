@@ -1,6 +1,7 @@
 use std::{
     cell::{Ref, RefCell, RefMut},
-    fmt::Debug,
+    fmt::{self, Debug, Display},
+    marker::PhantomData,
     rc::Rc,
 };
 
@@ -10,6 +11,8 @@ type Link<T> = Option<Rc<RefCell<Node<T>>>>;
 pub struct List<T> {
     head: Link<T>,
     tail: Link<T>,
+    // number of nodes in the list
+    len: usize,
 }
 
 impl<T: Debug> List<T> {
@@ -17,9 +20,29 @@ impl<T: Debug> List<T> {
         Self {
             head: None,
             tail: None,
+            len: 0,
         }
     }
 
+    // returns true if the list is empty
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    // returns true if the list has exactly one item
+    // which is when head and tail are the same node
+    pub fn is_single(&self) -> bool {
+        match (&self.head, &self.tail) {
+            (Some(head), Some(tail)) => Rc::ptr_eq(head, tail),
+            _ => false,
+        }
+    }
+
+    // returns the number of items in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
     // adds a new node to the front of the list
     pub fn push_front(&mut self, elem: T) {
         // create a new node
@@ -35,6 +58,7 @@ impl<T: Debug> List<T> {
         new_node.borrow_mut().next = old_head.clone();
         // set new_node as the head of the list
         self.head = Some(new_node);
+        self.len += 1;
         // if tail is none that means adding node for the first time
         // then tail now points to the new_node
         if self.tail.is_none() {
@@ -59,6 +83,7 @@ impl<T: Debug> List<T> {
         new_node.borrow_mut().prev = Some(old_tail.clone());
         // set new_node as the tail of the list
         self.tail = Some(new_node);
+        self.len += 1;
     }
 
     // removes a node from the front of the list
@@ -79,6 +104,7 @@ impl<T: Debug> List<T> {
             if self.head.is_none() {
                 self.tail = None;
             }
+            self.len -= 1;
             // take out the elem from the node and return it
             Rc::try_unwrap(node).unwrap().into_inner().elem
         })
@@ -102,6 +128,7 @@ impl<T: Debug> List<T> {
             if self.tail.is_none() {
                 self.head = None;
             }
+            self.len -= 1;
             // take out the elem from the node and return it
             Rc::try_unwrap(node).unwrap().into_inner().elem
         })
@@ -134,6 +161,513 @@ impl<T: Debug> List<T> {
             .as_ref()
             .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
     }
+
+    // returns the handle to the node at the given index
+    // returns None if the index is out of bounds
+    // walks from the head for the first half of the list
+    // and from the tail via prev for the second half
+    pub fn get(&self, index: usize) -> Option<NodeRef<'_, T>> {
+        if index >= self.len {
+            None
+        } else if index < self.len / 2 {
+            self.iter().nth(index)
+        } else {
+            self.iter().rev().nth(self.len - 1 - index)
+        }
+    }
+
+    // inserts a new node at the given position
+    // returns Err(usize) if the given index is larger than the list length
+    pub fn insert_at(&mut self, index: usize, elem: T) -> Result<(), usize> {
+        if index > self.len {
+            return Err(index);
+        }
+        if index == 0 {
+            self.push_front(elem);
+            return Ok(());
+        }
+        if index == self.len {
+            self.push_back(elem);
+            return Ok(());
+        }
+        // the new node goes in between prev_node and next_node
+        let next_node = self.node_at(index).unwrap();
+        let prev_node = next_node.borrow().prev.clone().unwrap();
+        self.insert_between(&prev_node, &next_node, elem);
+        Ok(())
+    }
+
+    // adds a new node in between two adjacent nodes in the list
+    fn insert_between(
+        &mut self,
+        prev_node: &Rc<RefCell<Node<T>>>,
+        next_node: &Rc<RefCell<Node<T>>>,
+        elem: T,
+    ) {
+        // create a new node pointing to both neighbours
+        let new_node = Rc::new(RefCell::new(Node::new(elem)));
+        new_node.borrow_mut().prev = Some(Rc::clone(prev_node));
+        new_node.borrow_mut().next = Some(Rc::clone(next_node));
+        // both neighbours now point to the new_node
+        prev_node.borrow_mut().next = Some(Rc::clone(&new_node));
+        next_node.borrow_mut().prev = Some(new_node);
+        self.len += 1;
+    }
+
+    // removes the node at the given position and returns its element
+    // returns None if the index is out of bounds
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        if index == 0 {
+            return self.pop_front();
+        }
+        if index == self.len - 1 {
+            return self.pop_back();
+        }
+        // the node is in the middle, so it has both neighbours
+        let node = self.node_at(index)?;
+        let prev_node = node.borrow_mut().prev.take().unwrap();
+        let next_node = node.borrow_mut().next.take().unwrap();
+        // neighbours now point to each other
+        prev_node.borrow_mut().next = Some(Rc::clone(&next_node));
+        next_node.borrow_mut().prev = Some(prev_node);
+        self.len -= 1;
+        // no other node points to the removed node anymore
+        // take out the elem from the node and return it
+        Some(Rc::try_unwrap(node).unwrap().into_inner().elem)
+    }
+
+    // reverse the list in place by swapping next and prev of every node
+    pub fn reverse(&mut self) {
+        let mut curr = self.head.clone();
+        while let Some(node) = curr {
+            let mut node = node.borrow_mut();
+            let node = &mut *node;
+            std::mem::swap(&mut node.next, &mut node.prev);
+            // prev now holds what used to be the next node
+            curr = node.prev.clone();
+        }
+        // finally the head becomes the tail and the tail becomes the head
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    // swaps the elements of the first and last nodes
+    pub fn swap_ends(&mut self) {
+        if let (Some(head), Some(tail)) = (self.head.as_ref(), self.tail.as_ref()) {
+            // with a single node head and tail are the same
+            if !Rc::ptr_eq(head, tail) {
+                std::mem::swap(&mut head.borrow_mut().elem, &mut tail.borrow_mut().elem);
+            }
+        }
+    }
+
+    // moves the first n elements to the back of the list
+    // n larger than the list length wraps around
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+        for _ in 0..n % self.len {
+            if let Some(elem) = self.pop_front() {
+                self.push_back(elem);
+            }
+        }
+    }
+
+    // copies the items from head to tail into a vec
+    // each node is borrowed only while its item is cloned
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().map(|node| node.borrow().clone()).collect()
+    }
+
+    // returns true if the list has an item equal to the target
+    // each node is borrowed only while its item is compared
+    pub fn contains(&self, target: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|node| *node.borrow() == *target)
+    }
+
+    // returns the handle to the first node from the front whose item matches the predicate
+    // nodes which do not match are released before moving on to the next
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<NodeRef<'_, T>> {
+        self.iter().find(|node| pred(&node.borrow()))
+    }
+
+    // returns the node at the given index
+    // walks from the nearer end of the list
+    fn node_at(&self, index: usize) -> Link<T> {
+        if index >= self.len {
+            return None;
+        }
+        let from_head = index < self.len / 2;
+        let (mut curr, steps) = if from_head {
+            (self.head.clone(), index)
+        } else {
+            (self.tail.clone(), self.len - 1 - index)
+        };
+        for _ in 0..steps {
+            let node = curr?;
+            let node = node.borrow();
+            curr = if from_head {
+                node.next.clone()
+            } else {
+                node.prev.clone()
+            };
+        }
+        curr
+    }
+
+    // moves all nodes of other at the back of the list in O(1)
+    // leaves other empty
+    pub fn append(&mut self, other: &mut List<T>) {
+        // if other is empty then nothing to move
+        let other_head = match other.head.take() {
+            None => return,
+            Some(node) => node,
+        };
+        let other_tail = other.tail.take();
+        match self.tail.take() {
+            // if tail is None that means list is empty and takes the nodes of other
+            None => self.head = Some(other_head),
+            Some(old_tail) => {
+                // link the tail and the head of other in both directions
+                other_head.borrow_mut().prev = Some(Rc::clone(&old_tail));
+                old_tail.borrow_mut().next = Some(other_head);
+            }
+        }
+        // tail of other becomes the tail of the list
+        self.tail = other_tail;
+        self.len += std::mem::replace(&mut other.len, 0);
+    }
+
+    // splits the list into two at the given index
+    // the list keeps the items before the index and
+    // returns a new list with the items from the index onwards
+    // panics if the index is larger than the list length
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let len = self.len;
+        assert!(
+            at <= len,
+            "split_off index (is {}) should be <= len (is {})",
+            at,
+            len
+        );
+        if at == 0 {
+            return std::mem::take(self);
+        }
+        if at == len {
+            return List::new();
+        }
+        // the node at the index becomes the head of the other list
+        let new_head = self.node_at(at).unwrap();
+        // cut the links between the node and its prev in both directions
+        let new_tail = new_head.borrow_mut().prev.take().unwrap();
+        new_tail.borrow_mut().next = None;
+        let other = List {
+            head: Some(new_head),
+            tail: self.tail.replace(new_tail),
+            len: len - at,
+        };
+        self.len = at;
+        other
+    }
+
+    // returns a cursor pointing to the first node of the list
+    pub fn cursor_front(&mut self) -> Cursor<'_, T> {
+        let current = self.head.clone();
+        Cursor {
+            list: self,
+            current,
+        }
+    }
+
+    // returns a cursor pointing to the last node of the list
+    pub fn cursor_back(&mut self) -> Cursor<'_, T> {
+        let current = self.tail.clone();
+        Cursor {
+            list: self,
+            current,
+        }
+    }
+
+    // returns Iter instance of the list
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head.clone(),
+            back: self.tail.clone(),
+            len: self.len,
+            list: PhantomData,
+        }
+    }
+}
+
+// returns an empty list
+impl<T: Debug> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// cloning builds a new list with its own nodes
+// so changes to one list are never seen by the other
+impl<T: Clone + Debug> Clone for List<T> {
+    fn clone(&self) -> Self {
+        let mut list = List::new();
+        for node in self.iter() {
+            list.push_back(node.borrow().clone());
+        }
+        list
+    }
+}
+
+// collecting into the list keeps the order of the iterator
+impl<T: Debug> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+// appends each item of the iterator at the back of the list
+impl<T: Debug> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+// formats the items from head to tail as [a, b, c]
+impl<T: Display + Debug> Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, node) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", *node.borrow())?;
+        }
+        write!(f, "]")
+    }
+}
+
+// compares both lists item by item from the head
+// each node is borrowed only while its item is compared
+impl<T: PartialEq + Debug> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| *a.borrow() == *b.borrow())
+    }
+}
+
+// Borrowing iterator over the list
+// yields a NodeRef handle to each node from head to tail,
+// or from tail to head when iterated from the back
+// it walks the links by cloning the Rc of the next node, so no borrow
+// of a node has to outlive the node it came from
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    // number of nodes not yet yielded from either end
+    len: usize,
+    list: PhantomData<&'a List<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = NodeRef<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.front.take().map(|node| {
+            self.front = node.borrow().next.clone();
+            NodeRef::new(node)
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.back.take().map(|node| {
+            self.back = node.borrow().prev.clone();
+            NodeRef::new(node)
+        })
+    }
+}
+
+// Handle to a node of the list
+// the item is borrowed through the handle only while it is used,
+// and the handle keeps the list borrowed, so no node can be popped
+// while a handle to it is still around
+pub struct NodeRef<'a, T> {
+    node: Rc<RefCell<Node<T>>>,
+    list: PhantomData<&'a List<T>>,
+}
+
+impl<'a, T> NodeRef<'a, T> {
+    fn new(node: Rc<RefCell<Node<T>>>) -> Self {
+        Self {
+            node,
+            list: PhantomData,
+        }
+    }
+
+    // returns the reference to the item of the node
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| &node.elem)
+    }
+
+    // returns mutable reference to the item of the node
+    pub fn borrow_mut(&self) -> RefMut<'_, T> {
+        RefMut::map(self.node.borrow_mut(), |node| &mut node.elem)
+    }
+}
+
+// Cursor to move around the list and edit it in place
+// besides pointing to a node, the cursor can be on the ghost position,
+// which sits in between the tail and the head of the list
+pub struct Cursor<'a, T> {
+    list: &'a mut List<T>,
+    // None when the cursor is on the ghost position
+    current: Link<T>,
+}
+
+impl<'a, T: Debug> Cursor<'a, T> {
+    // moves the cursor to the next node
+    // moves to the ghost position after the tail, and to the head from the ghost
+    pub fn move_next(&mut self) {
+        self.current = match self.current.take() {
+            None => self.list.head.clone(),
+            Some(node) => node.borrow().next.clone(),
+        };
+    }
+
+    // moves the cursor to the previous node
+    // moves to the ghost position before the head, and to the tail from the ghost
+    pub fn move_prev(&mut self) {
+        self.current = match self.current.take() {
+            None => self.list.tail.clone(),
+            Some(node) => node.borrow().prev.clone(),
+        };
+    }
+
+    // returns the reference to the element at the cursor
+    // returns None on the ghost position
+    pub fn peek(&self) -> Option<Ref<'_, T>> {
+        self.current
+            .as_ref()
+            .map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+
+    // returns mutable reference to the element at the cursor
+    // returns None on the ghost position
+    pub fn peek_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.current
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    // adds a new node right after the cursor
+    // on the ghost position the new node becomes the head of the list
+    pub fn insert_after(&mut self, elem: T) {
+        let node = match self.current.clone() {
+            None => return self.list.push_front(elem),
+            Some(node) => node,
+        };
+        let next_node = node.borrow().next.clone();
+        match next_node {
+            None => self.list.push_back(elem),
+            Some(next_node) => self.list.insert_between(&node, &next_node, elem),
+        }
+    }
+
+    // adds a new node right before the cursor
+    // on the ghost position the new node becomes the tail of the list
+    pub fn insert_before(&mut self, elem: T) {
+        let node = match self.current.clone() {
+            None => return self.list.push_back(elem),
+            Some(node) => node,
+        };
+        let prev_node = node.borrow().prev.clone();
+        match prev_node {
+            None => self.list.push_front(elem),
+            Some(prev_node) => self.list.insert_between(&prev_node, &node, elem),
+        }
+    }
+
+    // removes the node at the cursor and returns its element
+    // the cursor moves to the next node, returns None on the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+        let prev_node = node.borrow_mut().prev.take();
+        let next_node = node.borrow_mut().next.take();
+        // link the neighbours to each other, or move head and tail
+        // if the node was at either end of the list
+        match prev_node.as_ref() {
+            Some(prv) => prv.borrow_mut().next = next_node.clone(),
+            None => self.list.head = next_node.clone(),
+        }
+        match next_node.as_ref() {
+            Some(nxt) => nxt.borrow_mut().prev = prev_node.clone(),
+            None => self.list.tail = prev_node.clone(),
+        }
+        self.list.len -= 1;
+        self.current = next_node;
+        // no other node points to the removed node anymore
+        // take out the elem from the node and return it
+        Some(Rc::try_unwrap(node).unwrap().into_inner().elem)
+    }
+}
+
+// The cursor holds an Rc to the current node, which has to be released
+// before the list pops that node. Implementing Drop keeps the list borrowed
+// until the cursor is dropped, so the list can not be used while a cursor
+// is still holding on to one of its nodes.
+impl<'a, T> Drop for Cursor<'a, T> {
+    fn drop(&mut self) {
+        self.current.take();
+    }
+}
+
+// Consuming iterator over the list
+// pops the items from the front, or from the back when iterated from the back
+pub struct IntoIter<T: Debug>(List<T>);
+
+impl<T: Debug> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T: Debug> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T: Debug> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -251,4 +785,568 @@ mod tests {
         assert_eq!(&*list.peek_front().unwrap(), &4);
         assert_eq!(&*list.peek_back().unwrap(), &2);
     }
+
+    #[test]
+    fn test_default() {
+        let mut list = List::<i32>::default();
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list = List::new();
+        assert!(list.iter().next().is_none());
+        list.push_front(1);
+        let mut iter = list.iter();
+        assert_eq!(&*iter.next().unwrap().borrow(), &1);
+        assert!(iter.next().is_none());
+        list.push_front(2);
+        list.push_front(3);
+        let mut iter = list.iter();
+        assert_eq!(&*iter.next().unwrap().borrow(), &3);
+        assert_eq!(&*iter.next().unwrap().borrow(), &2);
+        assert_eq!(&*iter.next().unwrap().borrow(), &1);
+        assert!(iter.next().is_none());
+        // handles yielded by the iterator can be held together
+        let nodes: Vec<NodeRef<i32>> = list.iter().collect();
+        assert_eq!(
+            nodes.iter().map(|node| *node.borrow()).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        // and the item of each node can be changed through its handle
+        for node in nodes {
+            *node.borrow_mut() += 1;
+        }
+        assert_eq!(*list.peek_front().unwrap(), 4);
+        assert_eq!(*list.peek_back().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_iter_back() {
+        let mut list = List::new();
+        assert!(list.iter().next_back().is_none());
+        for i in 1..=4 {
+            list.push_back(i);
+        }
+        let items: Vec<i32> = list.iter().rev().map(|node| *node.borrow()).collect();
+        assert_eq!(items, vec![4, 3, 2, 1]);
+        // cursors from both ends meet in the middle without crossing
+        let mut iter = list.iter();
+        assert_eq!(&*iter.next().unwrap().borrow(), &1);
+        assert_eq!(&*iter.next_back().unwrap().borrow(), &4);
+        assert_eq!(&*iter.next().unwrap().borrow(), &2);
+        assert_eq!(&*iter.next_back().unwrap().borrow(), &3);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        // odd number of items
+        list.push_back(5);
+        let mut iter = list.iter();
+        assert_eq!(&*iter.next_back().unwrap().borrow(), &5);
+        assert_eq!(&*iter.next().unwrap().borrow(), &1);
+        assert_eq!(&*iter.next_back().unwrap().borrow(), &4);
+        assert_eq!(&*iter.next().unwrap().borrow(), &2);
+        assert_eq!(&*iter.next_back().unwrap().borrow(), &3);
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        let mut cloned = list.clone();
+        if let Some(mut elem) = cloned.peek_mut_front() {
+            *elem = 10;
+        }
+        assert_eq!(&*list.peek_front().unwrap(), &1);
+        assert_eq!(&*cloned.peek_front().unwrap(), &10);
+        assert_eq!(cloned.pop_front(), Some(10));
+        assert_eq!(cloned.pop_back(), Some(3));
+        assert_eq!(cloned.pop_back(), Some(2));
+        assert_eq!(cloned.pop_front(), None);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        list.push_front(1);
+        list.push_back(2);
+        list.push_front(3);
+        list.push_back(4);
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 4);
+        list.pop_front();
+        assert_eq!(list.len(), 3);
+        list.pop_back();
+        assert_eq!(list.len(), 2);
+        list.pop_back();
+        list.pop_front();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        // pop from empty list does not change the length
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list = List::new();
+        assert!(list.get(0).is_none());
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        assert_eq!(&*list.get(0).unwrap().borrow(), &0);
+        assert_eq!(&*list.get(1).unwrap().borrow(), &1);
+        assert_eq!(&*list.get(2).unwrap().borrow(), &2);
+        assert_eq!(&*list.get(3).unwrap().borrow(), &3);
+        assert_eq!(&*list.get(4).unwrap().borrow(), &4);
+        assert!(list.get(5).is_none());
+    }
+
+    #[test]
+    fn test_insert_at() {
+        let mut list = List::new();
+        assert_eq!(list.insert_at(1, 0), Err(1));
+        // insert at the head of an empty list
+        assert_eq!(list.insert_at(0, 2), Ok(()));
+        assert_eq!(list.insert_at(0, 0), Ok(()));
+        // insert at the tail
+        assert_eq!(list.insert_at(2, 4), Ok(()));
+        // insert in the middle
+        assert_eq!(list.insert_at(1, 1), Ok(()));
+        assert_eq!(list.insert_at(3, 3), Ok(()));
+        assert_eq!(list.insert_at(9, 9), Err(9));
+        assert_eq!(list.len(), 5);
+        let items: Vec<i32> = list.iter().map(|node| *node.borrow()).collect();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+        // prev links are fixed up as well
+        let items: Vec<i32> = list.iter().rev().map(|node| *node.borrow()).collect();
+        assert_eq!(items, vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_remove_at() {
+        let mut list = List::new();
+        assert_eq!(list.remove_at(0), None);
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        // remove the head
+        assert_eq!(list.remove_at(0), Some(0));
+        // remove a middle node
+        assert_eq!(list.remove_at(1), Some(2));
+        // remove the tail
+        assert_eq!(list.remove_at(2), Some(4));
+        // out of bounds
+        assert_eq!(list.remove_at(2), None);
+        assert_eq!(list.len(), 2);
+        let items: Vec<i32> = list.iter().map(|node| *node.borrow()).collect();
+        assert_eq!(items, vec![1, 3]);
+        let items: Vec<i32> = list.iter().rev().map(|node| *node.borrow()).collect();
+        assert_eq!(items, vec![3, 1]);
+        // remove the only element
+        assert_eq!(list.remove_at(1), Some(3));
+        assert_eq!(list.remove_at(0), Some(1));
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn test_cursor_move() {
+        let mut list = List::new();
+        for i in 1..=3 {
+            list.push_back(i);
+        }
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.peek().as_deref(), Some(&1));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.peek().as_deref(), Some(&3));
+        // moves past the tail to the ghost position
+        cursor.move_next();
+        assert!(cursor.peek().is_none());
+        // and wraps around to the head
+        cursor.move_next();
+        assert_eq!(cursor.peek().as_deref(), Some(&1));
+        cursor.move_prev();
+        assert!(cursor.peek().is_none());
+        cursor.move_prev();
+        assert_eq!(cursor.peek().as_deref(), Some(&3));
+        if let Some(mut elem) = cursor.peek_mut() {
+            *elem *= 10;
+        }
+        drop(cursor);
+        let cursor = list.cursor_back();
+        assert_eq!(cursor.peek().as_deref(), Some(&30));
+        drop(cursor);
+        let mut empty = List::<i32>::new();
+        let mut cursor = empty.cursor_front();
+        assert!(cursor.peek().is_none());
+        cursor.move_next();
+        assert!(cursor.peek().is_none());
+    }
+
+    #[test]
+    fn test_cursor_edit() {
+        let mut list = List::new();
+        for i in 1..=4 {
+            list.push_back(i);
+        }
+        let mut cursor = list.cursor_front();
+        cursor.move_next();
+        // cursor is on 2
+        cursor.insert_after(20);
+        cursor.insert_before(10);
+        assert_eq!(cursor.remove_current(), Some(2));
+        // cursor moves on to 20
+        assert_eq!(cursor.peek().as_deref(), Some(&20));
+        assert_eq!(cursor.remove_current(), Some(20));
+        assert_eq!(cursor.peek().as_deref(), Some(&3));
+        drop(cursor);
+        assert_eq!(list.len(), 4);
+        let items: Vec<i32> = list.iter().map(|node| *node.borrow()).collect();
+        assert_eq!(items, vec![1, 10, 3, 4]);
+
+        // edits at both ends
+        let mut cursor = list.cursor_back();
+        cursor.insert_after(5);
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.peek().as_deref(), Some(&5));
+        drop(cursor);
+        let mut cursor = list.cursor_front();
+        cursor.insert_before(0);
+        assert_eq!(cursor.remove_current(), Some(1));
+        // ghost position inserts at the ends
+        cursor.move_prev();
+        cursor.move_prev();
+        assert!(cursor.peek().is_none());
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_after(-1);
+        cursor.insert_before(6);
+        drop(cursor);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.pop_front(), Some(-1));
+        assert_eq!(list.pop_back(), Some(6));
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = List::<i32>::new();
+        list.reverse();
+        assert!(list.is_empty());
+        list.push_back(1);
+        list.reverse();
+        assert_eq!(&*list.peek_front().unwrap(), &1);
+        assert_eq!(&*list.peek_back().unwrap(), &1);
+        list.push_back(2);
+        list.push_back(3);
+        list.reverse();
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+
+        for i in 1..=4 {
+            list.push_back(i);
+        }
+        list.reverse();
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut list: List<i32> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+        list.extend(vec![4, 5]);
+        let items: Vec<i32> = list.iter().map(|node| *node.borrow()).collect();
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        list.extend(Vec::new());
+        assert_eq!(list.len(), 5);
+
+        let mut list: List<i32> = std::iter::empty().collect();
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        list.extend(std::iter::empty());
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+    }
+
+    #[test]
+    fn test_display() {
+        let list: List<i32> = List::new();
+        assert_eq!(list.to_string(), "[]");
+        let list: List<i32> = (1..=3).collect();
+        assert_eq!(list.to_string(), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut a = List::new();
+        let mut b = List::new();
+        assert_eq!(a, b);
+        for i in 1..=3 {
+            a.push_back(i);
+            b.push_back(i);
+        }
+        assert_eq!(a, b);
+        // comparing a list with itself does not double borrow
+        assert!(a == a);
+        b.push_back(4);
+        assert_ne!(a, b);
+        assert_ne!(b, a);
+        b.pop_back();
+        if let Some(mut elem) = b.peek_mut_back() {
+            *elem = 0;
+        }
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_swap_ends() {
+        let mut list = List::<i32>::new();
+        list.swap_ends();
+        assert!(list.is_empty());
+        list.push_back(1);
+        list.swap_ends();
+        assert_eq!(list.to_string(), "[1]");
+        list.extend(vec![2, 3, 4]);
+        list.swap_ends();
+        assert_eq!(list.to_string(), "[4, 2, 3, 1]");
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut list = List::<i32>::new();
+        list.rotate_left(3);
+        assert!(list.is_empty());
+        list.extend(vec![1, 2, 3, 4]);
+        list.rotate_left(1);
+        assert_eq!(list.to_string(), "[2, 3, 4, 1]");
+        list.rotate_left(5);
+        assert_eq!(list.to_string(), "[3, 4, 1, 2]");
+        list.rotate_left(4);
+        assert_eq!(list.to_string(), "[3, 4, 1, 2]");
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let mut list = List::new();
+        assert_eq!(list.to_vec(), Vec::<i32>::new());
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        // list is left intact
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop_front(), Some(1));
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut list = List::new();
+        assert!(!list.contains(&1));
+        list.extend(vec![1, 2, 3]);
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+    }
+
+    #[test]
+    fn test_into_iter_double_ended() {
+        let list: List<i32> = (1..=4).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(3));
+        // both ends met in the middle
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let list: List<i32> = (1..=4).collect();
+        let reversed: Vec<i32> = list.into_iter().rev().collect();
+        assert_eq!(reversed, vec![4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_find() {
+        let list: List<i32> = vec![1, 2, 3, 4].into_iter().collect();
+        assert_eq!(*list.find(|elem| *elem % 2 == 0).unwrap().borrow(), 2);
+        assert_eq!(*list.find(|elem| *elem > 3).unwrap().borrow(), 4);
+        assert!(list.find(|elem| *elem > 4).is_none());
+        // the list can still be borrowed while the found item is held
+        let found = list.find(|elem| *elem == 3).unwrap();
+        assert_eq!(*list.peek_front().unwrap(), 1);
+        assert!(list.contains(&3));
+        assert_eq!(*found.borrow(), 3);
+        drop(found);
+        let empty = List::<i32>::new();
+        assert!(empty.find(|_| true).is_none());
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list: List<i32> = (1..=5).collect();
+        let mut other = list.split_off(2);
+        assert_eq!(list.len(), 2);
+        assert_eq!(other.len(), 3);
+        // both halves can be walked in both directions
+        assert_eq!(list.to_vec(), vec![1, 2]);
+        assert_eq!(
+            list.iter()
+                .rev()
+                .map(|node| *node.borrow())
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert_eq!(other.to_vec(), vec![3, 4, 5]);
+        assert_eq!(
+            other
+                .iter()
+                .rev()
+                .map(|node| *node.borrow())
+                .collect::<Vec<_>>(),
+            vec![5, 4, 3]
+        );
+        assert!(list.tail.as_ref().unwrap().borrow().next.is_none());
+        assert!(other.head.as_ref().unwrap().borrow().prev.is_none());
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(other.pop_front(), Some(3));
+        assert_eq!(list.to_vec(), vec![1]);
+        assert_eq!(other.to_vec(), vec![4, 5]);
+    }
+
+    #[test]
+    fn test_split_off_bounds() {
+        let mut list: List<i32> = (1..=3).collect();
+        // at == len returns an empty list
+        let other = list.split_off(3);
+        assert!(other.is_empty());
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        // at == 0 moves everything
+        let mut other = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(other.to_vec(), vec![1, 2, 3]);
+        assert_eq!(other.len(), 3);
+        // split at the last node
+        let last = other.split_off(2);
+        assert_eq!(other.to_vec(), vec![1, 2]);
+        assert_eq!(*other.peek_back().unwrap(), 2);
+        assert_eq!(last.to_vec(), vec![3]);
+        assert_eq!(*last.peek_front().unwrap(), 3);
+        assert_eq!(*last.peek_back().unwrap(), 3);
+        assert!(List::<i32>::new().split_off(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "split_off index (is 4) should be <= len (is 3)")]
+    fn test_split_off_out_of_bounds() {
+        let mut list: List<i32> = (1..=3).collect();
+        list.split_off(4);
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list: List<i32> = (1..=2).collect();
+        let mut other: List<i32> = (3..=4).collect();
+        list.append(&mut other);
+        assert_eq!(list.len(), 4);
+        // the joined list can be walked in both directions
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+        assert_eq!(
+            list.iter()
+                .rev()
+                .map(|node| *node.borrow())
+                .collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+        assert!(other.is_empty());
+        assert_eq!(other.len(), 0);
+        assert!(other.tail.is_none());
+        // empty other is a no-op
+        list.append(&mut other);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+        // empty list takes the nodes of other
+        let mut empty = List::new();
+        empty.append(&mut list);
+        assert_eq!(empty.len(), 4);
+        assert_eq!(*empty.peek_front().unwrap(), 1);
+        assert_eq!(*empty.peek_back().unwrap(), 4);
+        assert!(list.is_empty());
+        assert!(list.tail.is_none());
+        // both empty
+        let mut a = List::<i32>::new();
+        let mut b = List::<i32>::new();
+        a.append(&mut b);
+        assert!(a.is_empty());
+        assert!(a.tail.is_none());
+        // both lists are still usable
+        empty.push_back(5);
+        list.push_back(6);
+        assert_eq!(empty.pop_back(), Some(5));
+        assert_eq!(empty.pop_back(), Some(4));
+        assert_eq!(empty.pop_front(), Some(1));
+        assert_eq!(list.to_vec(), vec![6]);
+    }
+
+    #[test]
+    fn test_is_single() {
+        let mut list = List::new();
+        assert!(!list.is_single());
+        list.push_back(1);
+        assert!(list.is_single());
+        list.push_front(2);
+        assert!(!list.is_single());
+        list.pop_back();
+        assert!(list.is_single());
+    }
 }
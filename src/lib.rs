@@ -3,3 +3,4 @@ pub mod first;
 pub mod fourth;
 pub mod second;
 pub mod third;
+pub mod traits;
@@ -1,12 +1,17 @@
-#[derive(Debug)]
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
 pub struct List<T> {
     head: Option<Box<Node<T>>>,
+    // number of nodes in the list
+    len: usize,
 }
 
 impl<T> List<T> {
     // creates an empty list
     pub fn new() -> Self {
-        Self { head: None }
+        Self { head: None, len: 0 }
     }
 
     // returns true if list is empty
@@ -14,15 +19,14 @@ impl<T> List<T> {
         self.head.is_none()
     }
 
+    // returns true if the list has exactly one item
+    pub fn is_single(&self) -> bool {
+        self.head.as_ref().is_some_and(|node| node.next.is_none())
+    }
+
     // returns length of the list
     pub fn len(&self) -> usize {
-        let mut len = 0;
-        let mut curr = self.head.as_ref();
-        while let Some(nxt) = curr {
-            len += 1;
-            curr = nxt.next.as_ref();
-        }
-        len
+        self.len
     }
 
     // push an item into the list
@@ -35,6 +39,14 @@ impl<T> List<T> {
         new_node.next = old_head;
         // set self.head as the new_node
         self.head = Some(Box::new(new_node));
+        self.len += 1;
+    }
+
+    // push an item at the back of the list
+    // walks upto the last node, so it takes longer as the list grows
+    pub fn push_back(&mut self, elem: T) {
+        *self.last_link() = Some(Box::new(Node::new(elem)));
+        self.len += 1;
     }
 
     // pop item from the list
@@ -42,10 +54,21 @@ impl<T> List<T> {
         // replace self.head with next of the node
         self.head.take().map(|node| {
             self.head = node.next;
+            self.len -= 1;
             node.elem
         })
     }
 
+    // pop item from the list only if the predicate returns true for it
+    // the predicate is not called when the list is empty
+    pub fn pop_if<F: FnOnce(&T) -> bool>(&mut self, pred: F) -> Option<T> {
+        if pred(self.peek()?) {
+            self.pop()
+        } else {
+            None
+        }
+    }
+
     // insert at a position
     // returns Err(usize) if the given index is larger than the list length
     pub fn insert_at(&mut self, index: usize, elem: T) -> Result<(), usize> {
@@ -65,9 +88,30 @@ impl<T> List<T> {
         let node_next = curr_head.next.take();
         new_node.next = node_next;
         curr_head.next = Some(Box::new(new_node));
+        self.len += 1;
         Ok(())
     }
 
+    // remove from a position
+    // returns Err(usize) if there is no item at the given index
+    pub fn remove_at(&mut self, index: usize) -> Result<T, usize> {
+        if index == 0 {
+            return self.pop().ok_or(index);
+        }
+        // first shift curr_head upto the node
+        // just previous to the position of removal
+        let mut curr_head = self.head.as_mut();
+        for _ in 1..index {
+            curr_head = curr_head.ok_or(index)?.next.as_mut();
+        }
+        let curr_head = curr_head.ok_or(index)?;
+        // take out the node to be removed and link its next to the previous node
+        let mut node = curr_head.next.take().ok_or(index)?;
+        curr_head.next = node.next.take();
+        self.len -= 1;
+        Ok(node.elem)
+    }
+
     // returns the reference of the first item in the list
     pub fn peek(&self) -> Option<&T> {
         self.head.as_ref().map(|node| &node.elem)
@@ -78,10 +122,64 @@ impl<T> List<T> {
         self.head.as_mut().map(|node| &mut node.elem)
     }
 
-    // returns IntoIter instance of the list
-    // takes ownership of the list
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter(self)
+    // returns the reference of the item at the given index
+    // returns None if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut curr = self.head.as_ref();
+        for _ in 0..index {
+            curr = curr?.next.as_ref();
+        }
+        curr.map(|node| &node.elem)
+    }
+
+    // returns the mutable reference of the item at the given index
+    // returns None if the index is out of bounds
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let mut curr = self.head.as_mut();
+        for _ in 0..index {
+            curr = curr?.next.as_mut();
+        }
+        curr.map(|node| &mut node.elem)
+    }
+
+    // returns the reference of the last item in the list
+    // walks upto the last node
+    pub fn peek_back(&self) -> Option<&T> {
+        self.iter().last()
+    }
+
+    // swaps the items at the given positions
+    // only the items are swapped, the nodes stay where they are
+    // returns Err(usize) with the index that has no item
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<(), usize> {
+        if i >= self.len {
+            return Err(i);
+        }
+        if j >= self.len {
+            return Err(j);
+        }
+        if i == j {
+            return Ok(());
+        }
+        let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+        // take both mutable references from the same walk
+        let mut iter = self.iter_mut();
+        let first = iter.nth(lo).unwrap();
+        let second = iter.nth(hi - lo - 1).unwrap();
+        std::mem::swap(first, second);
+        Ok(())
+    }
+
+    // returns the reference of the nth item from the head
+    // same as get but named after the iterator method
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    // returns the reference of the last item in the list
+    // same as peek_back but named after the iterator method
+    pub fn last(&self) -> Option<&T> {
+        self.peek_back()
     }
 
     // returns Iter instance of the list
@@ -95,19 +193,514 @@ impl<T> List<T> {
         let pointer = self.head.as_mut().map(|node| node.as_mut());
         IterMut { pointer }
     }
+
+    // builds a list from a vec keeping the same order
+    // so the first item of the vec becomes the head
+    pub fn from_vec(v: Vec<T>) -> Self {
+        v.into_iter().collect()
+    }
+
+    // copies the items from head to tail into a vec
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    // creates a persistent third::List with clones of the items
+    // the new list iterates the items in the same order as the list
+    pub fn to_persistent(&self) -> crate::third::List<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    // consumes the stack and moves its items into a fifth::List queue
+    // the items are pushed from head to tail, so the queue pops them in the
+    // same order the stack would, the top of the stack is popped first
+    // it is called reverse since the queue pops them in the reverse order
+    // they were pushed into the stack
+    pub fn reverse_into_queue(self) -> crate::fifth::List<T> {
+        self.into_iter().collect()
+    }
+
+    // returns true if the list has an item equal to the target
+    pub fn contains(&self, target: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|elem| elem == target)
+    }
+
+    // returns the index of the first item equal to the target
+    pub fn position(&self, target: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.iter().position(|elem| elem == target)
+    }
+
+    // returns the number of items for which the predicate returns true
+    pub fn count_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|elem| pred(elem)).count()
+    }
+
+    // returns true if the items read the same from both ends
+    // compares references to the items, so nothing is cloned
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let items: Vec<&T> = self.iter().collect();
+        let half = items.len() / 2;
+        items.iter().take(half).eq(items.iter().rev().take(half))
+    }
+
+    // moves all nodes of other at the back of the list
+    // leaves other empty
+    // walks upto the last node of the list, the nodes of other are moved as one chain
+    pub fn append(&mut self, other: &mut List<T>) {
+        *self.last_link() = other.head.take();
+        self.len += std::mem::replace(&mut other.len, 0);
+    }
+
+    // splits the list into two at the given index
+    // the list keeps the items before the index and
+    // returns a new list with the items from the index onwards
+    // panics if the index is larger than the list length
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let len = self.len();
+        assert!(
+            at <= len,
+            "split_off index (is {}) should be <= len (is {})",
+            at,
+            len
+        );
+        if at == 0 {
+            return std::mem::take(self);
+        }
+        // shift curr_head upto the last node which stays in the list
+        let mut curr_head = self.head.as_mut();
+        for _ in 1..at {
+            curr_head = curr_head.and_then(|node| node.next.as_mut());
+        }
+        let curr_head = curr_head.unwrap();
+        let mut other = List::new();
+        // if there are nodes after curr_head then move them to the other list
+        if let Some(node) = curr_head.next.take() {
+            other.head = Some(node);
+            other.len = len - at;
+            self.len = at;
+        }
+        other
+    }
+
+    // removes all items from the list
+    // nodes are dropped one by one so that a long chain
+    // does not overflow the stack with recursive drops
+    pub fn clear(&mut self) {
+        self.len = 0;
+        let mut curr = self.head.take();
+        while let Some(mut node) = curr {
+            curr = node.next.take();
+        }
+    }
+
+    // keeps the first len items and drops the rest
+    // does nothing if len is not less than the list length
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            self.split_off(len);
+        }
+    }
+
+    // splits the list at the first item equal to the target
+    // the list keeps the items before it and the returned list starts with it
+    // returns None and leaves the list unchanged if the target is not found
+    pub fn split_at_value(&mut self, target: &T) -> Option<List<T>>
+    where
+        T: PartialEq,
+    {
+        let at = self.position(target)?;
+        Some(self.split_off(at))
+    }
+
+    // keeps only the items for which the predicate returns true
+    // removed nodes are unlinked in place keeping the order of the rest
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut curr = &mut self.head;
+        while let Some(node) = curr.as_ref() {
+            if f(&node.elem) {
+                // keep the node and move on to the next
+                let node = curr.as_mut().unwrap();
+                curr = &mut node.next;
+            } else if let Some(mut node) = curr.take() {
+                // replace the node with its next, dropping the node
+                *curr = node.next.take();
+                self.len -= 1;
+            }
+        }
+    }
+
+    // removes the items for which the predicate returns true and returns them
+    // the rest of the items stay in the list in their original order
+    pub fn drain_filter<F: FnMut(&mut T) -> bool>(&mut self, mut pred: F) -> Vec<T> {
+        let mut removed = Vec::new();
+        let mut curr = &mut self.head;
+        while let Some(node) = curr.as_mut() {
+            if pred(&mut node.elem) {
+                // replace the node with its next and keep the item
+                let mut node = curr.take().unwrap();
+                *curr = node.next.take();
+                removed.push(node.elem);
+                self.len -= 1;
+            } else {
+                // keep the node and move on to the next
+                let node = curr.as_mut().unwrap();
+                curr = &mut node.next;
+            }
+        }
+        removed
+    }
+
+    // reverse the list in place by relinking the nodes
+    pub fn reverse(&mut self) {
+        let mut curr = self.head.take();
+        // holds the part of the list which is already reversed
+        let mut reversed = None;
+        while let Some(mut node) = curr {
+            // detach the rest of the list from the current node
+            curr = node.next.take();
+            // current node now points to the reversed part
+            node.next = reversed;
+            // current node becomes the head of the reversed part
+            reversed = Some(node);
+        }
+        self.head = reversed;
+    }
+
+    // sorts the list in ascending order
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(|a, b| a.cmp(b));
+    }
+
+    // sorts the list with the given comparator
+    // uses a stable merge sort over the nodes, so no item is moved or cloned
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) {
+        let head = self.head.take();
+        self.head = merge_sort(head, &mut cmp);
+    }
+
+    // merges other into the list, both lists must already be sorted
+    // the nodes of other are spliced in without moving or cloning any item
+    // on equal items the one from the list comes first, other is left empty
+    pub fn merge_sorted(&mut self, other: &mut List<T>)
+    where
+        T: Ord,
+    {
+        let left = self.head.take();
+        let right = other.head.take();
+        self.head = merge(left, right, &mut |a: &T, b: &T| a.cmp(b));
+        self.len += std::mem::replace(&mut other.len, 0);
+    }
+
+    // merges other into the list by alternating their nodes
+    // starting with the list, then other, then the list again and so on
+    // once one of them runs out the rest of the other is linked at the back
+    // the nodes are spliced in without cloning any item, other is left empty
+    pub fn interleave(&mut self, other: &mut List<T>) {
+        let mut left = self.head.take();
+        let mut right = other.head.take();
+        let mut head = None;
+        let mut tail = &mut head;
+        let mut from_left = true;
+        while let (Some(_), Some(_)) = (left.as_ref(), right.as_ref()) {
+            // pick the chain whose turn it is
+            let src = if from_left { &mut left } else { &mut right };
+            let mut node = src.take().unwrap();
+            *src = node.next.take();
+            tail = &mut tail.insert(node).next;
+            from_left = !from_left;
+        }
+        // one of the chains is exhausted, link whatever is left of the other
+        *tail = if left.is_some() { left } else { right };
+        self.head = head;
+        self.len += std::mem::replace(&mut other.len, 0);
+    }
+
+    // consumes the list and returns its items in ascending order
+    pub fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let mut items: Vec<T> = self.into_iter().collect();
+        items.sort();
+        items
+    }
+
+    // removes consecutive repeated items keeping the first of each run
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut curr = self.head.as_mut();
+        while let Some(node) = curr {
+            // unlink the following nodes as long as they are equal to the current one
+            while let Some(mut nxt) = node.next.take() {
+                if nxt.elem == node.elem {
+                    node.next = nxt.next.take();
+                    self.len -= 1;
+                } else {
+                    node.next = Some(nxt);
+                    break;
+                }
+            }
+            curr = node.next.as_mut();
+        }
+    }
+
+    // inserts an item into an ascending sorted list keeping it sorted
+    // the item is placed after any existing equal items
+    pub fn insert_sorted(&mut self, elem: T)
+    where
+        T: Ord,
+    {
+        // find the first item which is greater than the new one
+        match self.iter().position(|e| *e > elem) {
+            // index is always within the list so insertion can not fail
+            Some(index) => self.insert_at(index, elem).unwrap(),
+            // no greater item found, so the new one goes at the back
+            None => self.push_back(elem),
+        }
+    }
+
+    // creates a new list by applying f on each item from head to tail
+    // the list itself is left untouched
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> List<U> {
+        self.iter().map(f).collect()
+    }
+
+    // creates a new list with clones of the items for which f returns true
+    // unlike retain, the list itself is left untouched
+    pub fn filter<F: FnMut(&T) -> bool>(&self, mut f: F) -> List<T>
+    where
+        T: Clone,
+    {
+        self.iter().filter(|elem| f(elem)).cloned().collect()
+    }
+
+    // consumes the list and keeps the leading items while pred returns true
+    // the nodes are reused, the rest of the list is dropped
+    pub fn take_while<F: FnMut(&T) -> bool>(mut self, mut pred: F) -> List<T> {
+        let count = self.iter().take_while(|elem| pred(elem)).count();
+        self.truncate(count);
+        self
+    }
+
+    // consumes the list and drops the leading items while pred returns true
+    // the nodes of the rest of the list are reused
+    pub fn skip_while<F: FnMut(&T) -> bool>(mut self, mut pred: F) -> List<T> {
+        let count = self.iter().take_while(|elem| pred(elem)).count();
+        self.split_off(count)
+    }
+
+    // rotates the list to the left by n positions
+    // the first n nodes are moved to the back by relinking
+    // n larger than the length wraps around
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+        let n = n % self.len;
+        if n == 0 {
+            return;
+        }
+        // detach the nodes from n onwards and put the first n nodes behind them
+        let mut rest = self.split_off(n);
+        rest.append(self);
+        *self = rest;
+    }
+
+    // rotates the list to the right by n positions
+    // the last n nodes are moved to the front by relinking
+    // n larger than the length wraps around
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len < 2 {
+            return;
+        }
+        let n = n % self.len;
+        self.rotate_left(self.len - n);
+    }
+
+    // copies the items into consecutive groups of at most size items
+    // the last group is smaller if the length is not a multiple of size
+    // panics if size is 0, same as slice::chunks
+    pub fn chunks(&self, size: usize) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        assert!(size != 0, "chunk size must be non-zero");
+        let mut chunks = Vec::with_capacity(self.len.div_ceil(size));
+        let mut chunk = Vec::with_capacity(size);
+        for elem in self.iter() {
+            chunk.push(elem.clone());
+            // start a new group once the current one is full
+            if chunk.len() == size {
+                chunks.push(std::mem::replace(&mut chunk, Vec::with_capacity(size)));
+            }
+        }
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    // walks upto the last node and returns its empty next link
+    // returns the head link itself when the list is empty
+    fn last_link(&mut self) -> &mut Option<Box<Node<T>>> {
+        let mut curr = &mut self.head;
+        while let Some(node) = curr {
+            curr = &mut node.next;
+        }
+        curr
+    }
+}
+
+// Implement Default trait for List type
+// returns an empty list
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // Implement Drop trait for List type
 // clean up all nodes
 impl<T> Drop for List<T> {
     fn drop(&mut self) {
-        let mut curr = self.head.take();
-        while let Some(mut node) = curr {
-            curr = node.next.take();
+        self.clear();
+    }
+}
+
+// Implement Debug trait for List type
+// formats only the items from head to tail, the nodes are not shown
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Implement Clone trait for List type
+// builds a new chain of nodes in the same order
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        let mut list = List::new();
+        // link each cloned node after the previous one
+        let mut link = &mut list.head;
+        for elem in self.iter() {
+            link = &mut link.insert(Box::new(Node::new(elem.clone()))).next;
+        }
+        list.len = self.len;
+        list
+    }
+}
+
+// Implement PartialEq trait for List type
+// walks both lists together comparing each item
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = self.iter();
+        let mut right = other.iter();
+        loop {
+            match (left.next(), right.next()) {
+                (None, None) => return true,
+                (Some(a), Some(b)) if a == b => continue,
+                // either length or an item is different
+                _ => return false,
+            }
         }
     }
 }
 
+impl<T: Eq> Eq for List<T> {}
+
+// Implement Hash trait for List type
+// hashes the length and then the items from head to tail
+// so lists which are equal produce the same hash
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+// Implement FromIterator trait for List type
+// items are pushed at the back, so the head of the list
+// is the first item of the iterator and iterating the list
+// yields items in the same order as the source iterator
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+// Implement Extend trait for List type
+// appends each item of the iterator at the back of the list
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // walk upto the last node once and link each new node after the previous one
+        let mut link = self.last_link();
+        let mut added = 0;
+        for elem in iter {
+            link = &mut link.insert(Box::new(Node::new(elem))).next;
+            added += 1;
+        }
+        self.len += added;
+    }
+}
+
+// Implement Display trait for List type
+// formats the items from head to tail as [a, b, c]
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, elem) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", elem)?;
+        }
+        write!(f, "]")
+    }
+}
+
+// Implement Serialize trait for List type when serde feature is enabled
+// serializes the items from head to tail as a sequence
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for List<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+// Implement Deserialize trait for List type when serde feature is enabled
+// builds the list from a sequence keeping the same order
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for List<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(List::from_vec)
+    }
+}
+
 #[derive(Debug)]
 struct Node<T> {
     elem: T,
@@ -120,6 +713,63 @@ impl<T> Node<T> {
     }
 }
 
+// sorts the chain starting at head using merge sort
+// splits the chain into two halves, sorts both and merges them back
+fn merge_sort<T, F>(mut head: Option<Box<Node<T>>>, cmp: &mut F) -> Option<Box<Node<T>>>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // chains with zero or one node are already sorted
+    if head.as_ref().is_none_or(|node| node.next.is_none()) {
+        return head;
+    }
+    // move the slow pointer one step and the fast pointer two steps
+    // when fast reaches the end, slow is at the middle of the chain
+    let mut mid = 0;
+    let mut fast = head.as_ref().and_then(|node| node.next.as_ref());
+    while let Some(node) = fast.and_then(|node| node.next.as_ref()) {
+        mid += 1;
+        fast = node.next.as_ref();
+    }
+    // shift curr upto the last node of the first half and detach the second half
+    let mut curr = head.as_mut();
+    for _ in 0..mid {
+        curr = curr.and_then(|node| node.next.as_mut());
+    }
+    let second = curr.and_then(|node| node.next.take());
+    let first = merge_sort(head, cmp);
+    let second = merge_sort(second, cmp);
+    merge(first, second, cmp)
+}
+
+// merges two sorted chains into one sorted chain
+// on equal items the one from left comes first to keep the sort stable
+fn merge<T, F>(
+    mut left: Option<Box<Node<T>>>,
+    mut right: Option<Box<Node<T>>>,
+    cmp: &mut F,
+) -> Option<Box<Node<T>>>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut head = None;
+    let mut tail = &mut head;
+    while let (Some(l), Some(r)) = (left.as_ref(), right.as_ref()) {
+        // pick the chain whose first node comes next
+        let src = if cmp(&l.elem, &r.elem) == Ordering::Greater {
+            &mut right
+        } else {
+            &mut left
+        };
+        let mut node = src.take().unwrap();
+        *src = node.next.take();
+        tail = &mut tail.insert(node).next;
+    }
+    // one of the chains is exhausted, link whatever is left of the other
+    *tail = if left.is_some() { left } else { right };
+    head
+}
+
 #[derive(Debug)]
 pub struct IntoIter<T>(List<T>);
 
@@ -131,6 +781,44 @@ impl<T> Iterator for IntoIter<T> {
     fn next(&mut self) -> Option<Self::Item> {
         self.0.pop()
     }
+
+    // the list knows exactly how many items are left
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+// Implement IntoIterator for List
+// returns IntoIter instance of the list
+// takes ownership of the list
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+// Implement IntoIterator for reference of List
+// This will allow to use &list in for loops
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Implement IntoIterator for mutable reference of List
+// This will allow to use &mut list in for loops
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
 }
 
 pub struct Iter<'a, T> {
@@ -267,4 +955,943 @@ mod tests {
         assert_eq!(list.pop(), Some(2));
         assert_eq!(list.pop(), Some(3));
     }
+
+    #[test]
+    fn test_push_back() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push(0);
+        list.push_back(3);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.pop(), Some(0));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+        // push_back still works once the list becomes empty
+        list.push_back(4);
+        list.push(5);
+        list.push_back(6);
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(6));
+        assert_eq!(list.pop(), None);
+        // push_back goes after a node inserted at the end
+        list.push(1);
+        assert!(list.insert_at(1, 2).is_ok());
+        list.push_back(3);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_push_back_many() {
+        let mut list = List::new();
+        // each push_back walks the list, the order is kept however long it gets
+        for i in 0..1_000 {
+            list.push_back(i);
+        }
+        assert_eq!(list.len(), 1_000);
+        assert_eq!(list.peek(), Some(&0));
+        assert_eq!(list.iter().last(), Some(&999));
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<List<i32>>();
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        let handle = std::thread::spawn(move || {
+            list.push_back(3);
+            let mut popped = Vec::new();
+            while let Some(elem) = list.pop() {
+                popped.push(elem);
+            }
+            popped
+        });
+        assert_eq!(handle.join().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_covariance() {
+        fn shorten<'a>(list: List<&'static str>) -> List<&'a str> {
+            list
+        }
+        let mut list = List::new();
+        list.push_back("a");
+        let local = String::from("b");
+        let mut list = shorten(list);
+        list.push_back(&local);
+        assert_eq!(list.pop(), Some("a"));
+        assert_eq!(list.pop(), Some("b"));
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut list = List::new();
+        assert_eq!(format!("{:?}", list), "[]");
+        list.push_back(1);
+        list.push_back(2);
+        list.push(0);
+        assert_eq!(format!("{:?}", list), "[0, 1, 2]");
+    }
+
+    #[test]
+    fn test_peek_back() {
+        let mut list = List::new();
+        assert_eq!(list.peek_back(), None);
+        list.push(1);
+        assert_eq!(list.peek_back(), Some(&1));
+        assert_eq!(list.peek(), Some(&1));
+        list.push(2);
+        list.push(3);
+        assert_eq!(list.peek_back(), Some(&1));
+        assert_eq!(list.peek(), Some(&3));
+        list.push_back(4);
+        assert_eq!(list.peek_back(), Some(&4));
+        list.pop();
+        list.pop();
+        list.pop();
+        list.pop();
+        assert_eq!(list.peek_back(), None);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list = List::new();
+        assert_eq!(list.get(0), None);
+        list.push(3);
+        list.push(2);
+        list.push(1);
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&3));
+        assert_eq!(list.get(3), None);
+        assert_eq!(list.get_mut(3), None);
+        *list.get_mut(1).unwrap() += 10;
+        assert_eq!(list.get(1), Some(&12));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(12));
+    }
+
+    #[test]
+    fn test_remove_at() {
+        let mut list = List::new();
+        assert_eq!(list.remove_at(0), Err(0));
+        for i in 0..5 {
+            list.push_back(i);
+        }
+        // remove the head
+        assert_eq!(list.remove_at(0), Ok(0));
+        // remove a middle node
+        assert_eq!(list.remove_at(1), Ok(2));
+        // remove the last node
+        assert_eq!(list.remove_at(2), Ok(4));
+        // out of bounds
+        assert_eq!(list.remove_at(2), Err(2));
+        assert_eq!(list.remove_at(7), Err(7));
+        assert_eq!(list.len(), 2);
+        // the node before the removed one is the last node now
+        assert_eq!(list.peek_back(), Some(&3));
+        list.push_back(5);
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        let mut cloned = list.clone();
+        cloned.iter_mut().for_each(|e| *e *= 10);
+        cloned.push_back(0);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        let mut iter = cloned.iter();
+        assert_eq!(iter.next(), Some(&30));
+        assert_eq!(iter.next(), Some(&20));
+        assert_eq!(iter.next(), Some(&10));
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut a = List::new();
+        let mut b = List::new();
+        assert_eq!(a, b);
+        for i in 0..3 {
+            a.push_back(i);
+            b.push_back(i);
+        }
+        assert_eq!(a, b);
+        // differ only in length
+        b.push_back(3);
+        assert_ne!(a, b);
+        assert_ne!(b, a);
+        // differ in a middle item
+        b.pop();
+        *b.get_mut(1).unwrap() = 10;
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let mut list: List<i32> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&3));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), None);
+        let list: List<i32> = std::iter::empty().collect();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list = List::new();
+        list.extend(vec![1, 2]);
+        assert_eq!(list.len(), 2);
+        list.extend(vec![3, 4]);
+        list.extend(Vec::new());
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let mut list: List<i32> = (1..=3).collect();
+        let mut items = Vec::new();
+        for elem in &list {
+            items.push(*elem);
+        }
+        assert_eq!(items, vec![1, 2, 3]);
+        for elem in &mut list {
+            *elem *= 2;
+        }
+        let mut items = Vec::new();
+        for elem in list {
+            items.push(elem);
+        }
+        assert_eq!(items, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn test_display() {
+        let list: List<i32> = List::new();
+        assert_eq!(list.to_string(), "[]");
+        let list: List<i32> = (1..=3).collect();
+        assert_eq!(list.to_string(), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_vec() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        let list: List<i32> = List::from_vec(Vec::new());
+        assert!(list.is_empty());
+        assert_eq!(list.to_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_contains() {
+        let list = List::new();
+        assert!(!list.contains(&1));
+        assert_eq!(list.position(&1), None);
+        let list = List::from_vec(vec![1, 2, 3, 2]);
+        assert!(list.contains(&3));
+        assert_eq!(list.position(&3), Some(2));
+        assert!(!list.contains(&4));
+        assert_eq!(list.position(&4), None);
+        // returns the first match for duplicates
+        assert_eq!(list.position(&2), Some(1));
+    }
+
+    #[test]
+    fn test_append() {
+        // empty self
+        let mut a = List::new();
+        let mut b = List::from_vec(vec![1, 2]);
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(a.to_vec(), vec![1, 2]);
+        // empty other
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(a.to_vec(), vec![1, 2]);
+        // both non empty
+        let mut b = List::from_vec(vec![3, 4]);
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert_eq!(a.to_vec(), vec![1, 2, 3, 4]);
+        // the nodes of other are now at the back
+        assert_eq!(a.peek_back(), Some(&4));
+        a.push_back(5);
+        b.push_back(6);
+        assert_eq!(a.to_vec(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(b.to_vec(), vec![6]);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        let mut other = list.split_off(2);
+        assert_eq!(list.to_vec(), vec![1, 2]);
+        assert_eq!(other.to_vec(), vec![3, 4]);
+        // both lists end at the split
+        list.push_back(5);
+        other.push_back(6);
+        assert_eq!(list.to_vec(), vec![1, 2, 5]);
+        assert_eq!(other.to_vec(), vec![3, 4, 6]);
+        // split at len returns an empty list
+        let empty = list.split_off(3);
+        assert!(empty.is_empty());
+        assert_eq!(list.to_vec(), vec![1, 2, 5]);
+        // split at 0 moves everything
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(all.to_vec(), vec![1, 2, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "split_off index (is 3) should be <= len (is 2)")]
+    fn test_split_off_out_of_bounds() {
+        let mut list = List::from_vec(vec![1, 2]);
+        list.split_off(3);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        list.truncate(4);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+        list.truncate(10);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4]);
+        list.truncate(2);
+        assert_eq!(list.to_vec(), vec![1, 2]);
+        assert_eq!(list.peek_back(), Some(&2));
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.peek_back(), None);
+        list.push_back(1);
+        assert_eq!(list.to_vec(), vec![1]);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4, 5, 6, 7]);
+        list.retain(|e| e % 2 == 0);
+        assert_eq!(list.to_vec(), vec![2, 4, 6]);
+        // push_back goes after the last kept node
+        list.push_back(8);
+        assert_eq!(list.to_vec(), vec![2, 4, 6, 8]);
+
+        let mut list = List::from_vec(vec![1, 1, 2, 3, 3]);
+        list.retain(|e| *e == 2);
+        assert_eq!(list.to_vec(), vec![2]);
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.retain(|_| true);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(list.peek_back(), Some(&3));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list: List<i32> = List::new();
+        list.reverse();
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+
+        list.push(1);
+        list.reverse();
+        assert_eq!(list.to_vec(), vec![1]);
+        assert_eq!(list.peek_back(), Some(&1));
+
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        list.reverse();
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        // the old head is the last node now
+        list.push_back(0);
+        assert_eq!(list.to_vec(), vec![3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list: List<i32> = List::new();
+        list.sort();
+        assert!(list.is_empty());
+
+        let mut list = List::from_vec(vec![5, 1, 4, 2, 3, 2]);
+        list.sort();
+        assert_eq!(list.to_vec(), vec![1, 2, 2, 3, 4, 5]);
+        // the largest item is the last node now
+        assert_eq!(list.peek_back(), Some(&5));
+        list.push_back(6);
+        assert_eq!(list.to_vec(), vec![1, 2, 2, 3, 4, 5, 6]);
+
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.to_vec(), vec![6, 5, 4, 3, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_sort_stable() {
+        let mut list = List::from_vec(vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')]);
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            list.to_vec(),
+            vec![(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]
+        );
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = List::from_vec(vec![1, 1, 2, 3, 3, 3, 1]);
+        list.dedup();
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 1]);
+
+        let mut list = List::from_vec(vec![1, 2, 2, 2]);
+        list.dedup();
+        assert_eq!(list.to_vec(), vec![1, 2]);
+        // push_back goes after the last node once the run at the end is removed
+        list.push_back(3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+
+        let mut list = List::from_vec(vec![4, 4, 4]);
+        list.dedup();
+        assert_eq!(list.to_vec(), vec![4]);
+
+        let mut list: List<i32> = List::new();
+        list.dedup();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_cached_len() {
+        let mut list = List::new();
+        list.push(2);
+        list.push_back(3);
+        list.push(1);
+        assert_eq!(list.len(), 3);
+        assert!(list.insert_at(3, 4).is_ok());
+        assert_eq!(list.len(), 4);
+        assert!(list.insert_at(9, 4).is_err());
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.remove_at(1), Ok(2));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.remove_at(5), Err(5));
+        assert_eq!(list.len(), 3);
+        let mut other = List::from_vec(vec![5, 5, 6]);
+        list.append(&mut other);
+        assert_eq!(list.len(), 6);
+        assert_eq!(other.len(), 0);
+        list.dedup();
+        assert_eq!(list.len(), 5);
+        let rest = list.split_off(2);
+        assert_eq!(list.len(), 2);
+        assert_eq!(rest.len(), 3);
+        list.retain(|e| *e > 1);
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop(), None);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_into_iter_len() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        iter.next();
+        assert_eq!(iter.len(), 0);
+        iter.next();
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list = List::new();
+        for elem in [5, 3, 8, 1, 9, 3, 0, 7] {
+            list.insert_sorted(elem);
+        }
+        assert_eq!(list.to_vec(), vec![0, 1, 3, 3, 5, 7, 8, 9]);
+        assert_eq!(list.len(), 8);
+        assert_eq!(list.peek_back(), Some(&9));
+    }
+
+    #[test]
+    fn test_insert_sorted_stable() {
+        // ordered only by key, tag tells the insertion order apart
+        #[derive(Debug)]
+        struct Item {
+            key: i32,
+            tag: char,
+        }
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+        impl Eq for Item {}
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+        let mut list = List::new();
+        list.insert_sorted(Item { key: 2, tag: 'a' });
+        list.insert_sorted(Item { key: 1, tag: 'b' });
+        list.insert_sorted(Item { key: 2, tag: 'c' });
+        list.insert_sorted(Item { key: 1, tag: 'd' });
+        let tags: Vec<char> = list.iter().map(|item| item.tag).collect();
+        assert_eq!(tags, vec!['b', 'd', 'a', 'c']);
+    }
+
+    #[test]
+    fn test_map() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let strings = list.map(|e| e.to_string());
+        let mut iter = strings.iter();
+        assert_eq!(iter.next().map(String::as_str), Some("1"));
+        assert_eq!(iter.next().map(String::as_str), Some("2"));
+        assert_eq!(iter.next().map(String::as_str), Some("3"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(strings.len(), 3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_filter() {
+        let list = List::from_vec(vec![1, 2, 3, 4, 5, 6]);
+        let evens = list.filter(|e| e % 2 == 0);
+        assert_eq!(evens.to_vec(), vec![2, 4, 6]);
+        assert_eq!(evens.len(), 3);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+        let none = list.filter(|_| false);
+        assert!(none.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2,3]");
+        let back: List<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, list);
+        assert_eq!(back.peek_back(), Some(&3));
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+        let mut set = HashSet::new();
+        set.insert(List::from_vec(vec![1, 2, 3]));
+        set.insert((1..=3).collect::<List<i32>>());
+        assert_eq!(set.len(), 1);
+        set.insert(List::from_vec(vec![3, 2, 1]));
+        set.insert(List::from_vec(vec![1, 2]));
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&List::from_vec(vec![1, 2])));
+    }
+
+    #[test]
+    fn test_default() {
+        let list = List::<i32>::default();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_nth_and_last() {
+        let mut list = List::new();
+        assert_eq!(list.nth(0), None);
+        assert_eq!(list.last(), None);
+        list.push_back(1);
+        assert_eq!(list.nth(0), Some(&1));
+        assert_eq!(list.nth(1), None);
+        assert_eq!(list.last(), Some(&1));
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.nth(0), Some(&1));
+        assert_eq!(list.nth(1), Some(&2));
+        assert_eq!(list.nth(2), Some(&3));
+        assert_eq!(list.nth(3), None);
+        assert_eq!(list.last(), Some(&3));
+        list.push(0);
+        assert_eq!(list.nth(0), Some(&0));
+        assert_eq!(list.last(), Some(&3));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4, 5]);
+        // head with tail
+        assert_eq!(list.swap(0, 4), Ok(()));
+        assert_eq!(list.to_vec(), vec![5, 2, 3, 4, 1]);
+        assert_eq!(list.peek(), Some(&5));
+        assert_eq!(list.peek_back(), Some(&1));
+        // two middle items, order of indices does not matter
+        assert_eq!(list.swap(3, 1), Ok(()));
+        assert_eq!(list.to_vec(), vec![5, 4, 3, 2, 1]);
+        // same index is a no-op
+        assert_eq!(list.swap(2, 2), Ok(()));
+        assert_eq!(list.to_vec(), vec![5, 4, 3, 2, 1]);
+        // out of range
+        assert_eq!(list.swap(1, 5), Err(5));
+        assert_eq!(list.swap(7, 0), Err(7));
+        assert_eq!(list.swap(5, 5), Err(5));
+        assert_eq!(list.to_vec(), vec![5, 4, 3, 2, 1]);
+        assert_eq!(List::<i32>::new().swap(0, 0), Err(0));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4, 5]);
+        list.rotate_left(1);
+        assert_eq!(list.to_vec(), vec![2, 3, 4, 5, 1]);
+        assert_eq!(list.peek_back(), Some(&1));
+        list.rotate_right(1);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+        list.rotate_left(2);
+        assert_eq!(list.to_vec(), vec![3, 4, 5, 1, 2]);
+        list.rotate_right(3);
+        assert_eq!(list.to_vec(), vec![5, 1, 2, 3, 4]);
+        // n larger than the length wraps around
+        list.rotate_left(6);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+        list.rotate_right(12);
+        assert_eq!(list.to_vec(), vec![4, 5, 1, 2, 3]);
+        list.rotate_left(5);
+        assert_eq!(list.to_vec(), vec![4, 5, 1, 2, 3]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.peek_back(), Some(&3));
+        list.push_back(6);
+        assert_eq!(list.to_vec(), vec![4, 5, 1, 2, 3, 6]);
+        // empty and single item lists are not changed
+        let mut empty = List::<i32>::new();
+        empty.rotate_left(3);
+        empty.rotate_right(3);
+        assert!(empty.is_empty());
+        let mut single = List::from_vec(vec![1]);
+        single.rotate_left(3);
+        single.rotate_right(1);
+        assert_eq!(single.to_vec(), vec![1]);
+        assert_eq!(single.peek_back(), Some(&1));
+    }
+
+    #[test]
+    fn test_chunks() {
+        let list = List::from_vec(vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.chunks(2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+        assert_eq!(list.chunks(5), vec![vec![1, 2, 3, 4, 5]]);
+        assert_eq!(list.chunks(10), vec![vec![1, 2, 3, 4, 5]]);
+        assert_eq!(list.chunks(1).len(), 5);
+        // the list is left intact
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+        assert!(List::<i32>::new().chunks(3).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_zero() {
+        List::from_vec(vec![1, 2, 3]).chunks(0);
+    }
+
+    #[test]
+    fn test_drain_filter() {
+        let mut list = List::from_vec(vec![1, 2, 3, 4]);
+        let evens = list.drain_filter(|elem| *elem % 2 == 0);
+        assert_eq!(evens, vec![2, 4]);
+        assert_eq!(list.to_vec(), vec![1, 3]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.peek_back(), Some(&3));
+        list.push_back(5);
+        assert_eq!(list.to_vec(), vec![1, 3, 5]);
+        // the predicate can change the items which are kept
+        let drained = list.drain_filter(|elem| {
+            *elem *= 10;
+            *elem > 20
+        });
+        assert_eq!(drained, vec![30, 50]);
+        assert_eq!(list.to_vec(), vec![10]);
+        assert_eq!(list.peek_back(), Some(&10));
+        let all = list.drain_filter(|_| true);
+        assert_eq!(all, vec![10]);
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+        assert!(List::<i32>::new().drain_filter(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_is_single() {
+        let mut list = List::new();
+        assert!(!list.is_single());
+        list.push(1);
+        assert!(list.is_single());
+        list.push(2);
+        assert!(!list.is_single());
+        list.pop();
+        assert!(list.is_single());
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let mut list = List::from_vec(vec![1, 3, 5]);
+        let mut other = List::from_vec(vec![2, 4, 6]);
+        list.merge_sorted(&mut other);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 6);
+        assert_eq!(list.peek_back(), Some(&6));
+        assert!(other.is_empty());
+        assert_eq!(other.len(), 0);
+        assert_eq!(other.peek_back(), None);
+        // one of the lists is empty
+        list.merge_sorted(&mut other);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+        other.merge_sorted(&mut list);
+        assert_eq!(other.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(other.peek_back(), Some(&6));
+        assert!(list.is_empty());
+        other.push_back(7);
+        assert_eq!(other.peek_back(), Some(&7));
+    }
+
+    #[test]
+    fn test_merge_sorted_stable() {
+        // items are compared by the first value only
+        #[derive(Debug, PartialEq, Eq)]
+        struct Item(i32, char);
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        let mut list = List::from_vec(vec![Item(1, 'a'), Item(2, 'a')]);
+        let mut other = List::from_vec(vec![Item(1, 'b'), Item(2, 'b'), Item(3, 'b')]);
+        list.merge_sorted(&mut other);
+        let tags: Vec<(i32, char)> = list.iter().map(|item| (item.0, item.1)).collect();
+        assert_eq!(tags, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (3, 'b')]);
+    }
+
+    #[test]
+    fn test_into_sorted_vec() {
+        let list = List::from_vec(vec![4, 1, 5, 2, 3, 1]);
+        assert_eq!(list.into_sorted_vec(), vec![1, 1, 2, 3, 4, 5]);
+        assert_eq!(List::<i32>::new().into_sorted_vec(), Vec::<i32>::new());
+        // works for items which can not be cloned
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Item(i32);
+        let list = List::from_vec(vec![Item(3), Item(1), Item(2)]);
+        assert_eq!(list.into_sorted_vec(), vec![Item(1), Item(2), Item(3)]);
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        assert!(List::from_vec(vec![1, 2, 1]).is_palindrome());
+        assert!(List::from_vec(vec![1, 2, 2, 1]).is_palindrome());
+        assert!(!List::from_vec(vec![1, 2, 3]).is_palindrome());
+        assert!(!List::from_vec(vec![1, 2]).is_palindrome());
+        assert!(List::<i32>::new().is_palindrome());
+        assert!(List::from_vec(vec![1]).is_palindrome());
+        // works for items which can not be cloned
+        #[derive(PartialEq)]
+        struct Item(i32);
+        assert!(List::from_vec(vec![Item(1), Item(2), Item(1)]).is_palindrome());
+    }
+
+    #[test]
+    fn test_pop_if() {
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.pop_if(|elem| *elem == 1), Some(1));
+        assert_eq!(list.pop_if(|elem| *elem == 1), None);
+        // the list is untouched when the predicate is false
+        assert_eq!(list.to_vec(), vec![2, 3]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop_if(|elem| *elem < 5), Some(2));
+        assert_eq!(list.pop_if(|elem| *elem < 5), Some(3));
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+        // the predicate is not called on an empty list
+        let mut called = false;
+        assert_eq!(
+            list.pop_if(|_| {
+                called = true;
+                true
+            }),
+            None
+        );
+        assert!(!called);
+    }
+
+    #[test]
+    fn test_take_while_and_skip_while() {
+        let list = List::from_vec(vec![1, 2, 3, 4, 1]);
+        let taken = list.clone().take_while(|elem| *elem < 3);
+        assert_eq!(taken.to_vec(), vec![1, 2]);
+        assert_eq!(taken.len(), 2);
+        assert_eq!(taken.peek_back(), Some(&2));
+        let skipped = list.clone().skip_while(|elem| *elem < 3);
+        assert_eq!(skipped.to_vec(), vec![3, 4, 1]);
+        assert_eq!(skipped.len(), 3);
+        assert_eq!(skipped.peek_back(), Some(&1));
+        // predicate true for all or none of the items
+        assert_eq!(
+            list.clone().take_while(|_| true).to_vec(),
+            vec![1, 2, 3, 4, 1]
+        );
+        assert!(list.clone().take_while(|_| false).is_empty());
+        assert!(list.clone().skip_while(|_| true).is_empty());
+        assert_eq!(list.skip_while(|_| false).to_vec(), vec![1, 2, 3, 4, 1]);
+        assert!(List::<i32>::new().take_while(|_| true).is_empty());
+        assert!(List::<i32>::new().skip_while(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_count_where() {
+        let list = List::from_vec(vec![1, 2, 3, 4]);
+        assert_eq!(list.count_where(|elem| *elem % 2 == 0), 2);
+        assert_eq!(list.count_where(|elem| *elem > 0), 4);
+        assert_eq!(list.count_where(|elem| *elem > 4), 0);
+        assert_eq!(List::<i32>::new().count_where(|_| true), 0);
+    }
+
+    #[test]
+    fn test_reverse_into_queue() {
+        let mut stack = List::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        let mut queue = stack.reverse_into_queue();
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some(&3));
+        assert_eq!(queue.peek_back(), Some(&1));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), None);
+        assert!(List::<i32>::new().reverse_into_queue().is_empty());
+    }
+
+    #[test]
+    fn test_to_persistent() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let persistent = list.to_persistent();
+        assert!(persistent.iter().eq(list.iter()));
+        assert_eq!(persistent.head(), Some(&1));
+        assert_eq!(persistent.len(), 3);
+        // the list is left untouched
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert!(List::<i32>::new().to_persistent().is_empty());
+    }
+
+    #[test]
+    fn test_split_at_value() {
+        let mut list = List::from_vec(vec![1, 2, 3, 2, 4]);
+        // splits at the first match
+        let other = list.split_at_value(&2).unwrap();
+        assert_eq!(list.to_vec(), vec![1]);
+        assert_eq!(list.peek_back(), Some(&1));
+        assert_eq!(other.to_vec(), vec![2, 3, 2, 4]);
+        assert_eq!(other.len(), 4);
+        assert_eq!(other.peek_back(), Some(&4));
+        // target at the head moves everything
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        let other = list.split_at_value(&1).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(list.peek_back(), None);
+        assert_eq!(other.to_vec(), vec![1, 2, 3]);
+        // absent target leaves the list unchanged
+        let mut list = List::from_vec(vec![1, 2, 3]);
+        assert!(list.split_at_value(&5).is_none());
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert!(List::<i32>::new().split_at_value(&1).is_none());
+    }
+
+    #[test]
+    fn test_interleave() {
+        let mut list = List::from_vec(vec![1, 3, 5]);
+        let mut other = List::from_vec(vec![2, 4]);
+        list.interleave(&mut other);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.peek_back(), Some(&5));
+        assert!(other.is_empty());
+        assert_eq!(other.len(), 0);
+        assert_eq!(other.peek_back(), None);
+        // other is longer
+        let mut list = List::from_vec(vec![1, 3]);
+        let mut other = List::from_vec(vec![2, 4, 6, 8]);
+        list.interleave(&mut other);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 6, 8]);
+        assert_eq!(list.peek_back(), Some(&8));
+        // list is longer
+        let mut list = List::from_vec(vec![1, 3, 5, 7]);
+        let mut other = List::from_vec(vec![2]);
+        list.interleave(&mut other);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 5, 7]);
+        assert_eq!(list.peek_back(), Some(&7));
+        // either of them is empty
+        let mut empty = List::new();
+        list.interleave(&mut empty);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 5, 7]);
+        empty.interleave(&mut list);
+        assert_eq!(empty.to_vec(), vec![1, 2, 3, 5, 7]);
+        assert_eq!(empty.len(), 5);
+        assert!(list.is_empty());
+        empty.push_back(9);
+        assert_eq!(empty.peek_back(), Some(&9));
+    }
 }
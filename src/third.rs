@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{cmp::Ordering, rc::Rc};
 
 #[derive(Debug)]
 pub struct List<T> {
@@ -16,6 +16,11 @@ impl<T> List<T> {
         self.head.is_none()
     }
 
+    // returns true if the list has exactly one item
+    pub fn is_single(&self) -> bool {
+        self.head.as_ref().is_some_and(|node| node.next.is_none())
+    }
+
     // returns the length of the list
     pub fn len(&self) -> usize {
         let mut len = 0;
@@ -51,10 +56,104 @@ impl<T> List<T> {
         self.head.as_ref().map(|node| &node.elem)
     }
 
+    // returns reference to the element at the given index
+    // returns None if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let mut curr = self.head.as_deref();
+        for _ in 0..index {
+            curr = curr?.next.as_deref();
+        }
+        curr.map(|node| &node.elem)
+    }
+
+    // creates a list from a vec keeping the same order
+    // since prepend adds at the front, items are prepended in reverse
+    pub fn from_vec(v: Vec<T>) -> Self {
+        let mut list = List::new();
+        for elem in v.into_iter().rev() {
+            list = list.prepend(elem);
+        }
+        list
+    }
+
+    // copies the items from head to tail into a vec
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
+    // creates a mutable second::List with clones of the items
+    // the new list iterates the items in the same order as the list
+    pub fn to_mutable(&self) -> crate::second::List<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+
     pub fn iter(&self) -> Iter<T> {
         let pointer = self.head.as_deref();
         Iter { pointer }
     }
+
+    // creates a new list with the items in reverse order
+    // the old list is left unchanged
+    pub fn reverse(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut list = List::new();
+        for elem in self.iter() {
+            list = list.prepend(elem.clone());
+        }
+        list
+    }
+
+    // creates a new list with the items of the list followed by the items of other
+    // nodes of other are shared with the new list, but the items of the list
+    // are cloned into new nodes which are prepended in reverse order
+    pub fn append(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        let items: Vec<&T> = self.iter().collect();
+        let mut list = other.clone();
+        for elem in items.into_iter().rev() {
+            list = list.prepend(elem.clone());
+        }
+        list
+    }
+
+    // creates a new list with the first n items of the list
+    // returns a copy of the whole list if n is larger than the length
+    pub fn take(&self, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(self.iter().take(n).cloned().collect())
+    }
+
+    // creates a new list by skipping the first n items of the list
+    // the remaining nodes are shared with the old list
+    // returns an empty list if n is larger than the length
+    pub fn drop_first(&self, n: usize) -> Self {
+        let mut curr = self.head.as_ref();
+        for _ in 0..n {
+            curr = curr.and_then(|node| node.next.as_ref());
+        }
+        Self {
+            head: curr.cloned(),
+        }
+    }
+}
+
+// returns an empty list
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -68,6 +167,95 @@ impl<T> Drop for List<T> {
     }
 }
 
+// cloning the list only bumps the reference count of the head
+// both lists share all the nodes, which is safe since they are immutable
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+        }
+    }
+}
+
+// compares both lists item by item from the head
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = self.head.as_ref();
+        let mut right = other.head.as_ref();
+        loop {
+            match (left, right) {
+                (None, None) => return true,
+                // same node means the rest of both lists is shared
+                (Some(a), Some(b)) if Rc::ptr_eq(a, b) => return true,
+                (Some(a), Some(b)) if a.elem == b.elem => {
+                    left = a.next.as_ref();
+                    right = b.next.as_ref();
+                }
+                // either length or an item is different
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+// compares both lists item by item from the head,
+// when one list is a prefix of the other the shorter one is less
+impl<T: PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut left = self.head.as_ref();
+        let mut right = other.head.as_ref();
+        loop {
+            match (left, right) {
+                (None, None) => return Some(Ordering::Equal),
+                (None, Some(_)) => return Some(Ordering::Less),
+                (Some(_), None) => return Some(Ordering::Greater),
+                // same node means the rest of both lists is shared, as in eq
+                (Some(a), Some(b)) if Rc::ptr_eq(a, b) => return Some(Ordering::Equal),
+                (Some(a), Some(b)) => match a.elem.partial_cmp(&b.elem) {
+                    Some(Ordering::Equal) => {
+                        left = a.next.as_ref();
+                        right = b.next.as_ref();
+                    }
+                    ordering => return ordering,
+                },
+            }
+        }
+    }
+}
+
+// same walk as partial_cmp, so shared nodes are not compared either
+impl<T: Ord> Ord for List<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut left = self.head.as_ref();
+        let mut right = other.head.as_ref();
+        loop {
+            match (left, right) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(a), Some(b)) if Rc::ptr_eq(a, b) => return Ordering::Equal,
+                (Some(a), Some(b)) => match a.elem.cmp(&b.elem) {
+                    Ordering::Equal => {
+                        left = a.next.as_ref();
+                        right = b.next.as_ref();
+                    }
+                    ordering => return ordering,
+                },
+            }
+        }
+    }
+}
+
+// collecting into the list keeps the order of the iterator,
+// the first item of the iterator becomes the head of the list
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        List::from_vec(iter.into_iter().collect())
+    }
+}
+
 #[derive(Debug)]
 struct Node<T> {
     elem: T,
@@ -95,6 +283,40 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+// Consuming iterator over the list
+// nodes which are not shared with any other list are unwrapped and their
+// items are moved out, but a node which is still shared can not be taken
+// apart, so its item is cloned instead and the shared node is left intact
+// for the other lists. This is why consuming the list requires T: Clone.
+#[derive(Debug)]
+pub struct IntoIter<T>(List<T>);
+
+impl<T: Clone> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.head.take().map(|node| match Rc::try_unwrap(node) {
+            // the node is owned only by this list, move the item out
+            Ok(mut node) => {
+                self.0.head = node.next.take();
+                node.elem
+            }
+            // the node is shared, clone the item and keep the node alive
+            Err(node) => {
+                self.0.head = node.next.clone();
+                node.elem.clone()
+            }
+        })
+    }
+}
+
+impl<T: Clone> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,4 +353,219 @@ mod tests {
         assert_eq!(iter.next(), Some(&1));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_default() {
+        let list = List::<i32>::default();
+        assert!(list.is_empty());
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iter_shared() {
+        let list = List::new()
+            .prepend(String::from("a"))
+            .prepend(String::from("b"))
+            .prepend(String::from("c"));
+        // shares the nodes "b" and "a" with list
+        let shared = list.tail();
+        let items: Vec<String> = list.into_iter().collect();
+        assert_eq!(items, vec!["c", "b", "a"]);
+        // shared nodes are still intact
+        let mut iter = shared.iter();
+        assert_eq!(iter.next().map(String::as_str), Some("b"));
+        assert_eq!(iter.next().map(String::as_str), Some("a"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_clone() {
+        // returns how many lists are sharing the head node
+        fn head_count<T>(list: &List<T>) -> usize {
+            list.head.as_ref().map_or(0, Rc::strong_count)
+        }
+        let list = List::new().prepend(1).prepend(2);
+        assert_eq!(head_count(&list), 1);
+        let cloned = list.clone();
+        assert_eq!(head_count(&list), 2);
+        assert_eq!(head_count(&cloned), 2);
+        // prepend on one list does not affect the other
+        let longer = cloned.prepend(3);
+        assert_eq!(longer.len(), 3);
+        assert_eq!(longer.head(), Some(&3));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.head(), Some(&2));
+        drop(cloned);
+        drop(longer);
+        assert_eq!(head_count(&list), 1);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let list: List<i32> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        let list: List<i32> = std::iter::empty().collect();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_eq() {
+        // built independently
+        let a = List::new().prepend(1).prepend(2);
+        let b = List::new().prepend(1).prepend(2);
+        assert_eq!(a, b);
+        // sharing the same tail
+        let c = a.prepend(3);
+        let d = a.prepend(3);
+        assert_eq!(c, d);
+        assert_eq!(a, a.clone());
+        assert_ne!(c, a.prepend(4));
+        // differ in length
+        assert_ne!(a, c);
+        assert_ne!(c, a);
+        assert_ne!(a, a.tail());
+        assert_eq!(List::<i32>::new(), List::new());
+    }
+
+    #[test]
+    fn test_reverse() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        let reversed = list.reverse();
+        let mut iter = reversed.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+        assert!(List::<i32>::new().reverse().is_empty());
+    }
+
+    #[test]
+    fn test_append() {
+        let a = List::new().prepend(2).prepend(1);
+        let b = List::new().prepend(4).prepend(3);
+        let c = a.append(&b);
+        let mut iter = c.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), None);
+        // both lists remain unchanged
+        assert_eq!(a.len(), 2);
+        assert_eq!(a.head(), Some(&1));
+        assert_eq!(b.len(), 2);
+        assert_eq!(b.head(), Some(&3));
+        // appending empty lists
+        assert_eq!(a.append(&List::new()), a);
+        assert_eq!(List::new().append(&b), b);
+    }
+
+    #[test]
+    fn test_get() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.get(0), Some(&3));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&1));
+        assert_eq!(list.get(3), None);
+        assert_eq!(List::<i32>::new().get(0), None);
+    }
+
+    #[test]
+    fn test_vec() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        let list = List::<i32>::from_vec(Vec::new());
+        assert!(list.is_empty());
+        assert_eq!(list.to_vec(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_take_and_drop_first() {
+        let list = List::from_vec(vec![1, 2, 3]);
+        assert_eq!(list.take(0).to_vec(), Vec::<i32>::new());
+        assert_eq!(list.take(2).to_vec(), vec![1, 2]);
+        assert_eq!(list.take(3).to_vec(), vec![1, 2, 3]);
+        assert_eq!(list.take(10).to_vec(), vec![1, 2, 3]);
+        assert_eq!(list.drop_first(0), list);
+        assert_eq!(list.drop_first(1).to_vec(), vec![2, 3]);
+        assert!(list.drop_first(3).is_empty());
+        assert!(list.drop_first(10).is_empty());
+        // the old list is left unchanged
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_is_single() {
+        let list = List::new();
+        assert!(!list.is_single());
+        let list = list.prepend(1);
+        assert!(list.is_single());
+        let list = list.prepend(2);
+        assert!(!list.is_single());
+        assert!(list.tail().is_single());
+    }
+
+    #[test]
+    fn test_ord() {
+        let a = List::from_vec(vec![1, 2]);
+        let b = List::from_vec(vec![1, 3]);
+        let c = List::from_vec(vec![1]);
+        assert!(a < b);
+        assert!(b > a);
+        // a prefix is less than the longer list
+        assert!(c < a);
+        assert!(List::new() < c);
+        assert_eq!(a.cmp(&List::from_vec(vec![1, 2])), Ordering::Equal);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+        assert!(a <= List::from_vec(vec![1, 2]));
+        // lists can be kept in a sorted set
+        let set: std::collections::BTreeSet<List<i32>> =
+            vec![b.clone(), a.clone(), c.clone(), a.clone()]
+                .into_iter()
+                .collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![c, a, b]);
+        // NaN items in separate nodes can not be ordered
+        let nan = List::new().prepend(f64::NAN);
+        assert_eq!(nan.partial_cmp(&List::new().prepend(f64::NAN)), None);
+    }
+
+    #[test]
+    fn test_to_mutable() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        let mut mutable = list.to_mutable();
+        assert!(mutable.iter().eq(list.iter()));
+        assert_eq!(mutable.to_vec(), vec![3, 2, 1]);
+        assert_eq!(mutable.peek_back(), Some(&1));
+        // changes to the mutable list do not affect the persistent one
+        mutable.push_back(0);
+        *mutable.peek_mut().unwrap() = 30;
+        assert_eq!(mutable.to_vec(), vec![30, 2, 1, 0]);
+        assert_eq!(list.to_vec(), vec![3, 2, 1]);
+        assert!(List::<i32>::new().to_mutable().is_empty());
+    }
 }
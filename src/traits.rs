@@ -0,0 +1,107 @@
+use crate::{fifth, second};
+
+// Common operations of the singly linked lists
+// allows generic code to be written once over either list
+//
+// the lists differ in the order items come back from pop:
+// second::List is a stack, pop and peek work on the last pushed item (LIFO)
+// fifth::List is a queue, pop and peek work on the first pushed item (FIFO)
+pub trait SinglyList<T> {
+    // adds an item to the list
+    fn push(&mut self, elem: T);
+
+    // removes the next item from the list
+    // returns None if the list is empty
+    fn pop(&mut self) -> Option<T>;
+
+    // returns reference to the item which pop would remove next
+    fn peek(&self) -> Option<&T>;
+
+    // returns the number of items in the list
+    fn len(&self) -> usize;
+
+    // returns true if the list is empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// pushes to and pops from the head, so the list behaves as a stack
+impl<T> SinglyList<T> for second::List<T> {
+    fn push(&mut self, elem: T) {
+        second::List::push(self, elem)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        second::List::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        second::List::peek(self)
+    }
+
+    fn len(&self) -> usize {
+        second::List::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        second::List::is_empty(self)
+    }
+}
+
+// pushes to the tail and pops from the head, so the list behaves as a queue
+impl<T> SinglyList<T> for fifth::List<T> {
+    fn push(&mut self, elem: T) {
+        fifth::List::push(self, elem)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        fifth::List::pop(self)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        fifth::List::peek(self)
+    }
+
+    fn len(&self) -> usize {
+        fifth::List::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        fifth::List::is_empty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // pushes 1, 2, 3 and returns the items in the order pop gives them back
+    fn push_and_drain<L: SinglyList<i32>>(mut list: L) -> Vec<i32> {
+        assert!(list.is_empty());
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.pop(), None);
+        for i in 1..=3 {
+            list.push(i);
+        }
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 3);
+        let first = list.peek().copied();
+        let mut popped = Vec::new();
+        while let Some(elem) = list.pop() {
+            popped.push(elem);
+        }
+        assert_eq!(first, popped.first().copied());
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        popped
+    }
+
+    #[test]
+    fn test_singly_list() {
+        // stack gives back the last pushed item first
+        assert_eq!(push_and_drain(second::List::new()), vec![3, 2, 1]);
+        // queue gives back the first pushed item first
+        assert_eq!(push_and_drain(fifth::List::new()), vec![1, 2, 3]);
+    }
+}